@@ -11,10 +11,38 @@ pub struct WalletCreateEvent {
     pub smart_wallet: Pubkey,
     /// The owners of the created [SmartWallet].
     pub owners: Vec<Pubkey>,
+    /// The [SmartWallet::owner_weights] at the time of creation.
+    pub owner_weights: Vec<u64>,
     /// The [SmartWallet::threshold] at the time of creation.
     pub threshold: u64,
     /// The [SmartWallet::minimum_delay] at the time of creation.
     pub minimum_delay: i64,
+    /// The [SmartWallet::grace_period] at the time of creation.
+    pub grace_period: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [SmartWallet] account is resized.
+#[event]
+pub struct WalletResizeEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new maximum number of owners of the [SmartWallet].
+    pub max_owners: u8,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [SmartWallet] account is migrated to the current layout.
+#[event]
+pub struct WalletMigrateEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::layout_version].
+    pub layout_version: u64,
     /// The Unix timestamp when the event was emitted.
     pub timestamp: i64,
 }
@@ -27,6 +55,50 @@ pub struct WalletSetOwnersEvent {
     pub smart_wallet: Pubkey,
     /// The new owners of the [SmartWallet].
     pub owners: Vec<Pubkey>,
+    /// The new [SmartWallet::owner_weights].
+    pub owner_weights: Vec<u64>,
+    /// The new [SmartWallet::owner_permissions].
+    pub owner_permissions: Vec<u8>,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner is added to a [SmartWallet].
+#[event]
+pub struct WalletAddOwnerEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The added owner.
+    pub owner: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner is removed from a [SmartWallet].
+#[event]
+pub struct WalletRemoveOwnerEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The removed owner.
+    pub owner: Pubkey,
+    /// The new [SmartWallet::threshold].
+    pub threshold: u64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner of a [SmartWallet] is replaced.
+#[event]
+pub struct WalletSwapOwnerEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The owner that was replaced.
+    pub old_owner: Pubkey,
+    /// The owner that replaced `old_owner`.
+    pub new_owner: Pubkey,
     /// The Unix timestamp when the event was emitted.
     pub timestamp: i64,
 }
@@ -43,6 +115,170 @@ pub struct WalletChangeThresholdEvent {
     pub timestamp: i64,
 }
 
+/// Emitted when the owner weights of a [SmartWallet] are changed.
+#[event]
+pub struct WalletSetOwnerWeightsEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::owner_weights].
+    pub owner_weights: Vec<u64>,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the owner permissions of a [SmartWallet] are changed.
+#[event]
+pub struct WalletSetOwnerPermissionsEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::owner_permissions].
+    pub owner_permissions: Vec<u8>,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a proposer is allowed to propose transactions to a [SmartWallet].
+#[event]
+pub struct WalletAddProposerEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The allowed proposer.
+    pub proposer: Pubkey,
+    /// The [ProposerRecord].
+    pub proposer_record: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a proposer is no longer allowed to propose transactions to a [SmartWallet].
+#[event]
+pub struct WalletRemoveProposerEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The removed proposer.
+    pub proposer: Pubkey,
+    /// The closed [ProposerRecord].
+    pub proposer_record: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the execution policy of a [SmartWallet] is changed.
+#[event]
+pub struct WalletSetExecutionPolicyEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::execution_policy].
+    pub execution_policy: ExecutionPolicy,
+    /// The new [SmartWallet::keepers].
+    pub keepers: Vec<Pubkey>,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the guardian of a [SmartWallet] is changed.
+#[event]
+pub struct WalletSetGuardianEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::guardian].
+    pub guardian: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the pause authority of a [SmartWallet] is changed.
+#[event]
+pub struct WalletSetPauseAuthorityEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::pause_authority].
+    pub pause_authority: Pubkey,
+    /// The new [SmartWallet::pause_threshold].
+    pub pause_threshold: u64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [SmartWallet] is paused.
+#[event]
+pub struct WalletPauseEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The pause authority or owner which paused the [SmartWallet].
+    pub authority: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [SmartWallet] is unpaused.
+#[event]
+pub struct WalletUnpauseEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the threshold decay of a [SmartWallet] is changed.
+#[event]
+pub struct WalletSetThresholdDecayEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::threshold_decay_period].
+    pub decay_period: i64,
+    /// The new [SmartWallet::threshold_floor].
+    pub floor: u64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the timelock mode of a [SmartWallet] is changed.
+#[event]
+pub struct WalletSetTimelockModeEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::timelock_mode].
+    pub timelock_mode: TimelockMode,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the grace period of a [SmartWallet] is changed.
+#[event]
+pub struct WalletSetGracePeriodEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::grace_period].
+    pub grace_period: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the minimum delay of a [SmartWallet] is changed.
+#[event]
+pub struct WalletSetMinimumDelayEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The new [SmartWallet::minimum_delay].
+    pub minimum_delay: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
 /// Emitted when a [Transaction] is proposed.
 #[event]
 pub struct TransactionCreateEvent {
@@ -52,10 +288,49 @@ pub struct TransactionCreateEvent {
     /// The [Transaction].
     #[index]
     pub transaction: Pubkey,
-    /// The owner which proposed the transaction.
+    /// The owner or allowed proposer which proposed the transaction.
     pub proposer: Pubkey,
+    /// The [ProposerRecord] used to propose the transaction, or None if it
+    /// was proposed by an owner.
+    pub proposer_record: Option<Pubkey>,
     /// Instructions associated with the [Transaction].
     pub instructions: Vec<TXInstruction>,
+    /// The [Transaction::instructions_hash].
+    pub instructions_hash: [u8; 32],
+    /// The [Transaction::eta].
+    pub eta: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the instructions of a draft [Transaction] are edited.
+#[event]
+pub struct TransactionEditEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction].
+    #[index]
+    pub transaction: Pubkey,
+    /// The new instructions of the [Transaction].
+    pub instructions: Vec<TXInstruction>,
+    /// The new [Transaction::instructions_hash].
+    pub instructions_hash: [u8; 32],
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a draft [Transaction] is finalized.
+#[event]
+pub struct TransactionFinalizeEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction].
+    #[index]
+    pub transaction: Pubkey,
+    /// The final [Transaction::instructions_hash].
+    pub instructions_hash: [u8; 32],
     /// The [Transaction::eta].
     pub eta: i64,
     /// The Unix timestamp when the event was emitted.
@@ -73,6 +348,8 @@ pub struct TransactionApproveEvent {
     pub transaction: Pubkey,
     /// The owner which approved the transaction.
     pub owner: Pubkey,
+    /// The [Transaction::instructions_hash] of the approved transaction.
+    pub instructions_hash: [u8; 32],
     /// The Unix timestamp when the event was emitted.
     pub timestamp: i64,
 }
@@ -92,6 +369,38 @@ pub struct TransactionUnapproveEvent {
     pub timestamp: i64,
 }
 
+/// Emitted when a [Transaction] is cancelled.
+#[event]
+pub struct TransactionCancelEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction].
+    #[index]
+    pub transaction: Pubkey,
+    /// The proposer that cancelled the transaction.
+    pub proposer: Pubkey,
+    /// The account that received the refunded rent.
+    pub payer: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [Transaction] is closed.
+#[event]
+pub struct TransactionCloseEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction].
+    #[index]
+    pub transaction: Pubkey,
+    /// The account that received the refunded rent.
+    pub payer: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
 /// Emitted when a [Transaction] is executed.
 #[event]
 pub struct TransactionExecuteEvent {
@@ -101,8 +410,285 @@ pub struct TransactionExecuteEvent {
     /// The [Transaction] executed.
     #[index]
     pub transaction: Pubkey,
-    /// The owner that executed the transaction.
+    /// The account that executed the transaction.
+    pub executor: Pubkey,
+    /// The [Transaction::bounty] paid to the executor.
+    pub bounty: u64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a step of a [Transaction] is executed via
+/// [crate::smart_wallet::execute_transaction_step].
+#[event]
+pub struct TransactionExecuteStepEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction] executed.
+    #[index]
+    pub transaction: Pubkey,
+    /// The account that executed the step.
+    pub executor: Pubkey,
+    /// Index of the first instruction executed in this step.
+    pub start_index: u64,
+    /// The [Transaction::executed_count] after this step.
+    pub executed_count: u64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [Transaction] is rejected by an owner.
+#[event]
+pub struct TransactionRejectEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction].
+    #[index]
+    pub transaction: Pubkey,
+    /// The owner which rejected the transaction.
+    pub owner: Pubkey,
+    /// True if the threshold can no longer be reached, i.e. the [Transaction] is now rejected.
+    pub rejected: bool,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [Transaction] is vetoed by the guardian.
+#[event]
+pub struct TransactionVetoEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction] vetoed.
+    #[index]
+    pub transaction: Pubkey,
+    /// The guardian that vetoed the transaction.
+    pub guardian: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [TransactionBuffer] is initialized.
+#[event]
+pub struct TransactionBufferInitEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [TransactionBuffer].
+    #[index]
+    pub buffer: Pubkey,
+    /// The owner which proposed the buffer.
+    pub proposer: Pubkey,
+    /// The [TransactionBuffer::eta].
+    pub eta: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [TransactionBuffer] is finalized.
+#[event]
+pub struct TransactionBufferFinalizeEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [TransactionBuffer].
+    #[index]
+    pub buffer: Pubkey,
+    /// The number of [TransactionBundle]s in the buffer.
+    pub num_bundles: u8,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [TransactionBuffer] is approved.
+#[event]
+pub struct TransactionBufferApproveEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [TransactionBuffer].
+    #[index]
+    pub buffer: Pubkey,
+    /// The owner which approved the buffer.
+    pub owner: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [TransactionBuffer] is unapproved.
+#[event]
+pub struct TransactionBufferUnapproveEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [TransactionBuffer].
+    #[index]
+    pub buffer: Pubkey,
+    /// The owner that unapproved the buffer.
+    pub owner: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [TransactionBundle] of a [TransactionBuffer] is executed.
+#[event]
+pub struct TransactionBufferExecuteEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [TransactionBuffer].
+    #[index]
+    pub buffer: Pubkey,
+    /// The index of the executed [TransactionBundle].
+    pub bundle_index: u8,
+    /// The owner that executed the bundle.
     pub executor: Pubkey,
     /// The Unix timestamp when the event was emitted.
     pub timestamp: i64,
 }
+
+/// Emitted when a [TransactionBuffer] is closed.
+#[event]
+pub struct TransactionBufferCloseEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [TransactionBuffer].
+    #[index]
+    pub buffer: Pubkey,
+    /// The proposer of the buffer, which received the refunded rent.
+    pub proposer: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [TransactionBuffer] is vetoed.
+#[event]
+pub struct TransactionBufferVetoEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [TransactionBuffer] vetoed.
+    #[index]
+    pub buffer: Pubkey,
+    /// The guardian that vetoed the buffer.
+    pub guardian: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the [Recovery] configuration of a [SmartWallet] is set.
+#[event]
+pub struct RecoverySetEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Recovery].
+    pub recovery: Pubkey,
+    /// The new [Recovery::recovery_keys].
+    pub recovery_keys: Vec<Pubkey>,
+    /// The new [Recovery::threshold].
+    pub threshold: u64,
+    /// The new [Recovery::delay].
+    pub delay: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the recovery keys initiate a recovery of a [SmartWallet].
+#[event]
+pub struct RecoveryInitiateEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Recovery].
+    pub recovery: Pubkey,
+    /// The proposed owners of the [SmartWallet].
+    pub new_owners: Vec<Pubkey>,
+    /// The proposed threshold of the [SmartWallet].
+    pub new_threshold: u64,
+    /// The Unix timestamp after which the recovery may be completed.
+    pub completes_at: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner cancels a pending recovery of a [SmartWallet].
+#[event]
+pub struct RecoveryCancelEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Recovery].
+    pub recovery: Pubkey,
+    /// The owner which cancelled the recovery.
+    pub owner: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a recovery replaces the owners of a [SmartWallet].
+#[event]
+pub struct RecoveryCompleteEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Recovery].
+    pub recovery: Pubkey,
+    /// The new owners of the [SmartWallet].
+    pub owners: Vec<Pubkey>,
+    /// The new threshold of the [SmartWallet].
+    pub threshold: u64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the [Beneficiary] of a [SmartWallet] is set.
+#[event]
+pub struct BeneficiarySetEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Beneficiary].
+    pub beneficiary: Pubkey,
+    /// The new [Beneficiary::owners].
+    pub owners: Vec<Pubkey>,
+    /// The new [Beneficiary::threshold].
+    pub threshold: u64,
+    /// The new [Beneficiary::inactivity_period].
+    pub inactivity_period: i64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when the [Beneficiary] of a [SmartWallet] is removed.
+#[event]
+pub struct BeneficiaryRemoveEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The closed [Beneficiary].
+    pub beneficiary: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
+
+/// Emitted when a beneficiary claims an inactive [SmartWallet].
+#[event]
+pub struct WalletClaimEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Beneficiary].
+    pub beneficiary: Pubkey,
+    /// The beneficiary owner which claimed the [SmartWallet].
+    pub claimant: Pubkey,
+    /// The new owners of the [SmartWallet].
+    pub owners: Vec<Pubkey>,
+    /// The new threshold of the [SmartWallet].
+    pub threshold: u64,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
@@ -0,0 +1,59 @@
+//! Instruction handler for [smart_wallet::add_proposer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::add_proposer].
+pub fn handler(ctx: Context<AddProposer>, proposer: Pubkey) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let record = &mut ctx.accounts.proposer_record;
+    record.smart_wallet = ctx.accounts.smart_wallet.key();
+    record.proposer = proposer;
+    record.bump = *unwrap_int!(ctx.bumps.get("proposer_record"));
+    record.added_at = timestamp;
+
+    emit!(WalletAddProposerEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        proposer,
+        proposer_record: ctx.accounts.proposer_record.key(),
+        timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for AddProposer<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smart_wallet.to_account_info().is_signer,
+            "smart_wallet.is_signer"
+        );
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::add_proposer].
+#[derive(Accounts)]
+#[instruction(proposer: Pubkey)]
+pub struct AddProposer<'info> {
+    /// The [SmartWallet].
+    #[account(signer)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [ProposerRecord] to create.
+    #[account(
+        init,
+        seeds = [
+            b"GokiProposerRecord".as_ref(),
+            smart_wallet.key().to_bytes().as_ref(),
+            proposer.to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + ProposerRecord::LEN
+    )]
+    pub proposer_record: Account<'info, ProposerRecord>,
+    /// Payer to create the [ProposerRecord].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
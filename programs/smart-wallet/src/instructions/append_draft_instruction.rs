@@ -0,0 +1,89 @@
+//! Instruction handler for [smart_wallet::append_draft_instruction].
+
+use crate::*;
+use anchor_lang::system_program;
+
+/// Instruction handler for [smart_wallet::append_draft_instruction].
+pub fn handler(ctx: Context<EditDraftTransaction>, ix: TXInstruction) -> Result<()> {
+    let mut instructions = ctx.accounts.transaction.instructions.clone();
+    instructions.push(ix);
+    ctx.accounts.set_instructions(instructions)
+}
+
+impl<'info> EditDraftTransaction<'info> {
+    /// Replaces the instructions of the draft [Transaction], growing the account
+    /// if they no longer fit and resetting all approvals.
+    pub(crate) fn set_instructions(&mut self, instructions: Vec<TXInstruction>) -> Result<()> {
+        let transaction_info = self.transaction.to_account_info();
+        let num_owners = self.smart_wallet.owners.len();
+        let new_space = Transaction::space(instructions.clone(), num_owners);
+        if new_space > transaction_info.data_len() {
+            let required_lamports = Rent::get()?
+                .minimum_balance(new_space)
+                .saturating_sub(transaction_info.lamports());
+            if required_lamports > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.payer.to_account_info(),
+                            to: transaction_info.clone(),
+                        },
+                    ),
+                    required_lamports,
+                )?;
+            }
+            transaction_info.realloc(new_space, true)?;
+        }
+
+        let tx = &mut self.transaction;
+        tx.instructions = instructions.clone();
+        tx.instructions_hash = tx.compute_instructions_hash()?;
+        // any edit invalidates the approvals of the previous instructions
+        tx.signers = vec![false; num_owners];
+        tx.rejections = vec![false; num_owners];
+        tx.revoked = vec![false; num_owners];
+        tx.owner_set_seqno = self.smart_wallet.owner_set_seqno;
+        tx.threshold_reached_at = 0;
+
+        emit!(TransactionEditEvent {
+            smart_wallet: self.smart_wallet.key(),
+            transaction: self.transaction.key(),
+            instructions,
+            instructions_hash: self.transaction.instructions_hash,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+}
+
+/// This validator is used for appending, replacing, and removing draft instructions.
+impl<'info> Validate<'info> for EditDraftTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.transaction.smart_wallet);
+        assert_keys_eq!(self.proposer, self.transaction.proposer, "proposer");
+        // the proposer may have been removed or lost permission since creating the draft
+        self.smart_wallet
+            .try_owner_index_with_permission(self.proposer.key(), PERMISSION_PROPOSE)?;
+        invariant!(self.transaction.draft, TransactionNotDraft);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::append_draft_instruction],
+/// [smart_wallet::replace_draft_instruction], and [smart_wallet::remove_draft_instruction].
+#[derive(Accounts)]
+pub struct EditDraftTransaction<'info> {
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The draft [Transaction].
+    #[account(mut, has_one = smart_wallet)]
+    pub transaction: Account<'info, Transaction>,
+    /// The proposer of the [Transaction].
+    pub proposer: Signer<'info>,
+    /// Payer of the additional rent, if any.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
@@ -7,19 +7,31 @@ pub fn handler(ctx: Context<Approve>) -> Result<()> {
     let owner_index = ctx
         .accounts
         .smart_wallet
-        .try_owner_index(ctx.accounts.owner.key())?;
-    ctx.accounts.transaction.signers[owner_index] = true;
+        .try_owner_index_with_permission(ctx.accounts.owner.key(), PERMISSION_APPROVE)?;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.signers[owner_index] = true;
+    // approving withdraws any prior rejection
+    *unwrap_opt!(tx.rejections.get_mut(owner_index), InvalidOwner) = false;
+    *unwrap_opt!(tx.revoked.get_mut(owner_index), InvalidOwner) = false;
+    tx.update_threshold_reached_at(&ctx.accounts.smart_wallet, current_ts)?;
+
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.owner.key(), current_ts);
 
     emit!(TransactionApproveEvent {
         smart_wallet: ctx.accounts.smart_wallet.key(),
         transaction: ctx.accounts.transaction.key(),
         owner: ctx.accounts.owner.key(),
-        timestamp: Clock::get()?.unix_timestamp
+        instructions_hash: ctx.accounts.transaction.instructions_hash,
+        timestamp: current_ts
     });
     Ok(())
 }
 
-/// This validator is used for both approve and unapprove.
+/// This validator is used for approve, unapprove, and reject.
 impl<'info> Validate<'info> for Approve<'info> {
     fn validate(&self) -> Result<()> {
         // The TX in question should belong to the smart wallet.
@@ -34,6 +46,17 @@ impl<'info> Validate<'info> for Approve<'info> {
 
         // no point in approving/unapproving if the TX is already executed.
         invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+        // votes can no longer change once execution has started.
+        invariant!(self.transaction.executed_count == 0, ExecutionStarted);
+
+        // drafts may still change, so they cannot be voted on.
+        invariant!(!self.transaction.draft, TransactionIsDraft);
+
+        // rejections are final.
+        invariant!(!self.transaction.is_rejected(), TransactionRejected);
+
+        // vetoed transactions can never be executed.
+        invariant!(!self.transaction.is_vetoed(), TransactionVetoed);
 
         Ok(())
     }
@@ -43,6 +66,7 @@ impl<'info> Validate<'info> for Approve<'info> {
 #[derive(Accounts)]
 pub struct Approve<'info> {
     /// The [SmartWallet].
+    #[account(mut)]
     pub smart_wallet: Account<'info, SmartWallet>,
     /// The [Transaction].
     #[account(mut, has_one = smart_wallet)]
@@ -0,0 +1,61 @@
+//! Instruction handler for [smart_wallet::approve_transaction_buffer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::approve_transaction_buffer].
+pub fn handler(ctx: Context<ApproveTransactionBuffer>) -> Result<()> {
+    let owner_index = ctx
+        .accounts
+        .smart_wallet
+        .try_owner_index_with_permission(ctx.accounts.owner.key(), PERMISSION_APPROVE)?;
+    ctx.accounts.buffer.signers[owner_index] = true;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .buffer
+        .update_threshold_reached_at(&ctx.accounts.smart_wallet, current_ts)?;
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.owner.key(), current_ts);
+
+    emit!(TransactionBufferApproveEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        buffer: ctx.accounts.buffer.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: current_ts
+    });
+    Ok(())
+}
+
+/// This validator is used for both approve and unapprove.
+impl<'info> Validate<'info> for ApproveTransactionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.buffer.smart_wallet);
+        invariant!(
+            self.smart_wallet.owner_set_seqno == self.buffer.owner_set_seqno,
+            OwnerSetChanged
+        );
+
+        // the contents of the buffer must be final before it can be voted on.
+        invariant!(self.buffer.is_finalized(), BufferBundleNotFinalized);
+        invariant!(!self.buffer.is_vetoed(), TransactionVetoed);
+
+        // approvals are final once execution of the buffer has started.
+        invariant!(!self.buffer.is_executing(), BufferBundleExecuted);
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::approve_transaction_buffer].
+#[derive(Accounts)]
+pub struct ApproveTransactionBuffer<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [TransactionBuffer].
+    #[account(mut, has_one = smart_wallet)]
+    pub buffer: Account<'info, TransactionBuffer>,
+    /// One of the smart_wallet owners. Checked in the handler.
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,13 @@
+//! Instruction handler for [smart_wallet::approve_with_hash].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::approve_with_hash].
+pub fn handler(ctx: Context<Approve>, instructions_hash: [u8; 32]) -> Result<()> {
+    invariant!(
+        instructions_hash == ctx.accounts.transaction.instructions_hash,
+        InstructionsHashMismatch
+    );
+
+    instructions::approve::handler(ctx)
+}
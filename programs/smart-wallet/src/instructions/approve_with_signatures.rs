@@ -0,0 +1,152 @@
+//! Instruction handler for [smart_wallet::approve_with_signatures].
+
+use crate::*;
+use anchor_lang::solana_program::{
+    ed25519_program, instruction::Instruction, sysvar::instructions as sysvar_instructions,
+};
+
+/// Size of the header of an Ed25519 program instruction:
+/// the number of signatures and a padding byte.
+const ED25519_HEADER_LEN: usize = 2;
+/// Size of the offsets of each signature in an Ed25519 program instruction.
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Instruction index which refers to the Ed25519 program instruction itself.
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Instruction handler for [smart_wallet::approve_with_signatures].
+pub fn handler(ctx: Context<ApproveWithSignatures>) -> Result<()> {
+    let transaction_key = ctx.accounts.transaction.key();
+    let message = ctx.accounts.transaction.approval_message(transaction_key)?;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    let instructions_sysvar = &ctx.accounts.instructions_sysvar;
+    let current_index = sysvar_instructions::load_current_index_checked(instructions_sysvar)?;
+
+    let mut approvers: Vec<Pubkey> = vec![];
+    for index in 0..current_index {
+        let ix =
+            sysvar_instructions::load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        for (signer, signed_message) in ed25519_signed_messages(&ix)? {
+            if signed_message == message && !approvers.contains(&signer) {
+                approvers.push(signer);
+            }
+        }
+    }
+
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    let tx = &mut ctx.accounts.transaction;
+    let mut num_approved: usize = 0;
+    for owner in approvers {
+        let owner_index = match smart_wallet.owner_index_opt(owner) {
+            Some(owner_index) if smart_wallet.has_permission(owner_index, PERMISSION_APPROVE) => {
+                owner_index
+            }
+            _ => continue,
+        };
+        // owners which unapproved or rejected must approve on-chain again
+        if *unwrap_opt!(tx.revoked.get(owner_index), InvalidOwner) {
+            continue;
+        }
+        let signed = unwrap_opt!(tx.signers.get_mut(owner_index), InvalidOwner);
+        // owners which already approved are not counted again
+        if *signed {
+            continue;
+        }
+        *signed = true;
+        smart_wallet.record_owner_activity(owner, current_ts);
+        num_approved += 1;
+
+        emit!(TransactionApproveEvent {
+            smart_wallet: smart_wallet.key(),
+            transaction: transaction_key,
+            owner,
+            instructions_hash: tx.instructions_hash,
+            timestamp: current_ts
+        });
+    }
+    invariant!(num_approved > 0, NoApprovalSignatures);
+
+    tx.update_threshold_reached_at(smart_wallet, current_ts)?;
+    Ok(())
+}
+
+/// Parses the signer and message of each signature verified by an Ed25519 program instruction.
+///
+/// Only signatures whose key, signature, and message are all stored in the
+/// instruction itself are accepted.
+fn ed25519_signed_messages(ix: &Instruction) -> Result<Vec<(Pubkey, &[u8])>> {
+    let data = &ix.data;
+    invariant!(
+        data.len() >= ED25519_HEADER_LEN,
+        InvalidSignatureInstruction
+    );
+    let num_signatures = data[0] as usize;
+
+    let read_u16 = |offset: usize| -> Result<u16> {
+        let bytes = unwrap_opt!(data.get(offset..offset + 2), InvalidSignatureInstruction);
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    };
+
+    let mut signed_messages = Vec::with_capacity(num_signatures);
+    for signature_index in 0..num_signatures {
+        let start = ED25519_HEADER_LEN + signature_index * ED25519_OFFSETS_LEN;
+        let signature_instruction_index = read_u16(start + 2)?;
+        let public_key_offset = read_u16(start + 4)? as usize;
+        let public_key_instruction_index = read_u16(start + 6)?;
+        let message_data_offset = read_u16(start + 8)? as usize;
+        let message_data_size = read_u16(start + 10)? as usize;
+        let message_instruction_index = read_u16(start + 12)?;
+
+        invariant!(
+            signature_instruction_index == ED25519_CURRENT_INSTRUCTION
+                && public_key_instruction_index == ED25519_CURRENT_INSTRUCTION
+                && message_instruction_index == ED25519_CURRENT_INSTRUCTION,
+            InvalidSignatureInstruction
+        );
+
+        let public_key = unwrap_opt!(
+            data.get(public_key_offset..public_key_offset + 32),
+            InvalidSignatureInstruction
+        );
+        let message = unwrap_opt!(
+            data.get(message_data_offset..message_data_offset + message_data_size),
+            InvalidSignatureInstruction
+        );
+        signed_messages.push((Pubkey::new(public_key), message));
+    }
+    Ok(signed_messages)
+}
+
+impl<'info> Validate<'info> for ApproveWithSignatures<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.transaction.smart_wallet);
+        invariant!(
+            self.smart_wallet.owner_set_seqno == self.transaction.owner_set_seqno,
+            OwnerSetChanged
+        );
+        invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+        invariant!(self.transaction.executed_count == 0, ExecutionStarted);
+        invariant!(!self.transaction.draft, TransactionIsDraft);
+        invariant!(!self.transaction.is_rejected(), TransactionRejected);
+        invariant!(!self.transaction.is_vetoed(), TransactionVetoed);
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::approve_with_signatures].
+#[derive(Accounts)]
+pub struct ApproveWithSignatures<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Transaction].
+    #[account(mut, has_one = smart_wallet)]
+    pub transaction: Account<'info, Transaction>,
+    /// CHECK: The instructions sysvar, holding the Ed25519 program instructions.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
@@ -0,0 +1,40 @@
+//! Instruction handler for [smart_wallet::cancel_recovery].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::cancel_recovery].
+pub fn handler(ctx: Context<CancelRecovery>) -> Result<()> {
+    let recovery = &mut ctx.accounts.recovery;
+    recovery.initiated_at = 0;
+    recovery.new_owners = vec![];
+    recovery.new_threshold = 0;
+
+    emit!(RecoveryCancelEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        recovery: ctx.accounts.recovery.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CancelRecovery<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.recovery.smart_wallet);
+        self.smart_wallet.try_owner_index(self.owner.key())?;
+        invariant!(self.recovery.is_pending(), RecoveryNotPending);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::cancel_recovery].
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Recovery] of the [SmartWallet].
+    #[account(mut)]
+    pub recovery: Account<'info, Recovery>,
+    /// One of the current owners.
+    pub owner: Signer<'info>,
+}
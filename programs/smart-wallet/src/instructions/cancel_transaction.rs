@@ -0,0 +1,49 @@
+//! Instruction handler for [smart_wallet::cancel_transaction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::cancel_transaction].
+pub fn handler(ctx: Context<CancelTransaction>) -> Result<()> {
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.num_closed_transactions =
+        unwrap_int!(smart_wallet.num_closed_transactions.checked_add(1));
+
+    emit!(TransactionCancelEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        proposer: ctx.accounts.proposer.key(),
+        payer: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CancelTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.transaction.smart_wallet);
+        assert_keys_eq!(self.proposer, self.transaction.proposer, "proposer");
+        assert_keys_eq!(self.payer, self.transaction.payer, "payer");
+
+        // executed transactions cannot be cancelled.
+        // partially executed transactions may be, aborting their remaining instructions.
+        invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::cancel_transaction].
+#[derive(Accounts)]
+pub struct CancelTransaction<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Transaction] to cancel.
+    #[account(mut, has_one = smart_wallet, close = payer)]
+    pub transaction: Account<'info, Transaction>,
+    /// The proposer of the [Transaction].
+    pub proposer: Signer<'info>,
+    /// CHECK: The payer of the [Transaction], which receives the refunded rent.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
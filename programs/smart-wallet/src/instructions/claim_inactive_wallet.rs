@@ -0,0 +1,72 @@
+//! Instruction handler for [smart_wallet::claim_inactive_wallet].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::claim_inactive_wallet].
+pub fn handler(ctx: Context<ClaimInactiveWallet>) -> Result<()> {
+    let owners = ctx.accounts.beneficiary.owners.clone();
+    let threshold = ctx.accounts.beneficiary.threshold;
+    let max_owners =
+        SmartWallet::max_owners(ctx.accounts.smart_wallet.to_account_info().data_len());
+    invariant!(owners.len() <= max_owners, TooManyOwners);
+
+    // replace the owner set like [smart_wallet::set_owners].
+    let current_ts = Clock::get()?.unix_timestamp;
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.owners = owners.clone();
+    smart_wallet.owner_weights = vec![];
+    smart_wallet.owner_permissions = vec![];
+    smart_wallet.threshold = threshold;
+    smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
+    smart_wallet.last_activity_ts = current_ts;
+
+    emit!(WalletSetOwnersEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        owners: owners.clone(),
+        owner_weights: vec![],
+        owner_permissions: vec![],
+        timestamp: current_ts
+    });
+    emit!(WalletClaimEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        claimant: ctx.accounts.claimant.key(),
+        owners,
+        threshold,
+        timestamp: current_ts
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ClaimInactiveWallet<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.beneficiary.smart_wallet);
+        invariant!(
+            self.beneficiary.owners.contains(&self.claimant.key()),
+            "claimant must be a beneficiary owner"
+        );
+
+        let current_ts = Clock::get()?.unix_timestamp;
+        invariant!(
+            current_ts
+                >= unwrap_int!(self
+                    .smart_wallet
+                    .last_activity_ts
+                    .checked_add(self.beneficiary.inactivity_period)),
+            WalletNotInactive
+        );
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::claim_inactive_wallet].
+#[derive(Accounts)]
+pub struct ClaimInactiveWallet<'info> {
+    /// The [SmartWallet] to claim.
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Beneficiary] of the [SmartWallet].
+    pub beneficiary: Account<'info, Beneficiary>,
+    /// One of the [Beneficiary::owners].
+    pub claimant: Signer<'info>,
+}
@@ -0,0 +1,48 @@
+//! Instruction handler for [smart_wallet::close_legacy_transaction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::close_legacy_transaction].
+pub fn handler(ctx: Context<CloseLegacyTransaction>) -> Result<()> {
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.num_closed_transactions =
+        unwrap_int!(smart_wallet.num_closed_transactions.checked_add(1));
+
+    let transaction = ctx.accounts.transaction.to_account_info();
+    let proposer = ctx.accounts.proposer.to_account_info();
+    let refund = transaction.lamports();
+    **proposer.lamports.borrow_mut() = unwrap_int!(proposer.lamports().checked_add(refund));
+    **transaction.lamports.borrow_mut() = 0;
+    transaction.try_borrow_mut_data()?.fill(0);
+
+    emit!(TransactionCloseEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        payer: ctx.accounts.proposer.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseLegacyTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        let tx = LegacyTransaction::try_from_account_data(&self.transaction.try_borrow_data()?)?;
+        assert_keys_eq!(self.smart_wallet, tx.smart_wallet);
+        assert_keys_eq!(self.proposer, tx.proposer, "proposer");
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::close_legacy_transaction].
+#[derive(Accounts)]
+pub struct CloseLegacyTransaction<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// CHECK: The [Transaction] to close, which is checked to use the legacy layout.
+    #[account(mut, owner = crate::ID)]
+    pub transaction: UncheckedAccount<'info>,
+    /// CHECK: The proposer of the [Transaction], which receives the refunded rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
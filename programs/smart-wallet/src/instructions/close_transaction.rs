@@ -0,0 +1,59 @@
+//! Instruction handler for [smart_wallet::close_transaction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::close_transaction].
+pub fn handler(ctx: Context<CloseTransaction>) -> Result<()> {
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.num_closed_transactions =
+        unwrap_int!(smart_wallet.num_closed_transactions.checked_add(1));
+
+    emit!(TransactionCloseEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        payer: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.transaction.smart_wallet);
+        assert_keys_eq!(self.payer, self.transaction.payer, "payer");
+
+        // Executed, vetoed, and rejected transactions may always be closed.
+        if self.transaction.executed_at != -1
+            || self.transaction.is_vetoed()
+            || self.transaction.is_rejected()
+        {
+            return Ok(());
+        }
+
+        // Otherwise, the transaction must be past its grace period,
+        // even if some of its instructions have been executed.
+        let eta = self.transaction.eta;
+        invariant!(eta != NO_ETA, TransactionNotClosable);
+        let current_ts = Clock::get()?.unix_timestamp;
+        invariant!(
+            current_ts > unwrap_int!(eta.checked_add(self.smart_wallet.grace_period)),
+            TransactionNotClosable
+        );
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::close_transaction].
+#[derive(Accounts)]
+pub struct CloseTransaction<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Transaction] to close.
+    #[account(mut, has_one = smart_wallet, close = payer)]
+    pub transaction: Account<'info, Transaction>,
+    /// CHECK: The payer of the [Transaction], which receives the refunded rent.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,50 @@
+//! Instruction handler for [smart_wallet::close_transaction_buffer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::close_transaction_buffer].
+pub fn handler(ctx: Context<CloseTransactionBuffer>) -> Result<()> {
+    emit!(TransactionBufferCloseEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        buffer: ctx.accounts.buffer.key(),
+        proposer: ctx.accounts.proposer.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CloseTransactionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.buffer.smart_wallet);
+        assert_keys_eq!(self.proposer, self.buffer.proposer, "proposer");
+
+        // Executed and vetoed buffers may always be closed.
+        if self.buffer.is_executed() || self.buffer.is_vetoed() {
+            return Ok(());
+        }
+
+        // Otherwise, the buffer must be past its grace period.
+        let eta = self.buffer.eta;
+        invariant!(eta != NO_ETA, TransactionNotClosable);
+        let current_ts = Clock::get()?.unix_timestamp;
+        invariant!(
+            current_ts > unwrap_int!(eta.checked_add(self.smart_wallet.grace_period)),
+            TransactionNotClosable
+        );
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::close_transaction_buffer].
+#[derive(Accounts)]
+pub struct CloseTransactionBuffer<'info> {
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [TransactionBuffer] to close.
+    #[account(mut, has_one = smart_wallet, close = proposer)]
+    pub buffer: Account<'info, TransactionBuffer>,
+    /// CHECK: The proposer of the [TransactionBuffer], which receives the refunded rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
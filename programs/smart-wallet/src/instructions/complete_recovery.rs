@@ -0,0 +1,68 @@
+//! Instruction handler for [smart_wallet::complete_recovery].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::complete_recovery].
+pub fn handler(ctx: Context<CompleteRecovery>) -> Result<()> {
+    let new_owners = ctx.accounts.recovery.new_owners.clone();
+    let new_threshold = ctx.accounts.recovery.new_threshold;
+    validators::validate_owner_set(&new_owners, &[], &[], new_threshold)?;
+
+    // replace the owner set like [smart_wallet::set_owners].
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.owners = new_owners.clone();
+    smart_wallet.owner_weights = vec![];
+    smart_wallet.owner_permissions = vec![];
+    smart_wallet.threshold = new_threshold;
+    smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
+
+    let recovery = &mut ctx.accounts.recovery;
+    recovery.initiated_at = 0;
+    recovery.new_owners = vec![];
+    recovery.new_threshold = 0;
+
+    // the recovered owners are active.
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.smart_wallet.last_activity_ts = timestamp;
+
+    emit!(WalletSetOwnersEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        owners: new_owners.clone(),
+        owner_weights: vec![],
+        owner_permissions: vec![],
+        timestamp
+    });
+    emit!(RecoveryCompleteEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        recovery: ctx.accounts.recovery.key(),
+        owners: new_owners,
+        threshold: new_threshold,
+        timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CompleteRecovery<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.recovery.smart_wallet);
+        invariant!(self.recovery.is_pending(), RecoveryNotPending);
+
+        let current_ts = Clock::get()?.unix_timestamp;
+        invariant!(
+            current_ts >= unwrap_int!(self.recovery.initiated_at.checked_add(self.recovery.delay)),
+            RecoveryNotReady
+        );
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::complete_recovery].
+#[derive(Accounts)]
+pub struct CompleteRecovery<'info> {
+    /// The [SmartWallet] to recover.
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Recovery] of the [SmartWallet].
+    #[account(mut)]
+    pub recovery: Account<'info, Recovery>,
+}
@@ -0,0 +1,71 @@
+//! Instruction handler for [smart_wallet::create_beneficiary].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::create_beneficiary].
+pub fn handler(
+    ctx: Context<CreateBeneficiary>,
+    owners: Vec<Pubkey>,
+    threshold: u64,
+    inactivity_period: i64,
+) -> Result<()> {
+    validators::validate_owner_set(&owners, &[], &[], threshold)?;
+    invariant!(inactivity_period > 0, "inactivity period must be positive");
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    beneficiary.smart_wallet = ctx.accounts.smart_wallet.key();
+    beneficiary.bump = *unwrap_int!(ctx.bumps.get("beneficiary"));
+    beneficiary.inactivity_period = inactivity_period;
+    beneficiary.threshold = threshold;
+    beneficiary.owners = owners.clone();
+
+    // the inactivity period counts from when the beneficiary is configured.
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.smart_wallet.last_activity_ts = timestamp;
+
+    emit!(BeneficiarySetEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        owners,
+        threshold,
+        inactivity_period,
+        timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateBeneficiary<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smart_wallet.to_account_info().is_signer,
+            "smart_wallet.is_signer"
+        );
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::create_beneficiary].
+#[derive(Accounts)]
+#[instruction(max_owners: u8)]
+pub struct CreateBeneficiary<'info> {
+    /// The [SmartWallet].
+    #[account(mut, signer)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Beneficiary] to create.
+    #[account(
+        init,
+        seeds = [
+            b"GokiBeneficiary".as_ref(),
+            smart_wallet.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Beneficiary::space(max_owners)
+    )]
+    pub beneficiary: Account<'info, Beneficiary>,
+    /// Payer to create the [Beneficiary].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,55 @@
+//! Instruction handler for [smart_wallet::create_draft_transaction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::create_draft_transaction].
+pub fn handler(ctx: Context<CreateTransaction>, instructions: Vec<TXInstruction>) -> Result<()> {
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    smart_wallet
+        .try_owner_index_with_permission(ctx.accounts.proposer.key(), PERMISSION_PROPOSE)?;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    // drafts cannot be approved, so the proposer only signs on finalization
+    let mut signers = Vec::new();
+    signers.resize(smart_wallet.owners.len(), false);
+
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    let index = smart_wallet.num_transactions;
+    smart_wallet.num_transactions = unwrap_int!(smart_wallet.num_transactions.checked_add(1));
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.smart_wallet = smart_wallet.key();
+    tx.index = index;
+    tx.bump = *unwrap_int!(ctx.bumps.get("transaction"));
+
+    tx.proposer = ctx.accounts.proposer.key();
+    tx.payer = ctx.accounts.payer.key();
+    tx.instructions = instructions.clone();
+    tx.signers = signers.clone();
+    tx.rejections = vec![false; signers.len()];
+    tx.revoked = vec![false; signers.len()];
+    tx.owner_set_seqno = smart_wallet.owner_set_seqno;
+    tx.eta = NO_ETA;
+
+    tx.executor = Pubkey::default();
+    tx.executed_at = -1;
+    tx.created_at = current_ts;
+    tx.draft = true;
+    tx.instructions_hash = tx.compute_instructions_hash()?;
+
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.proposer.key(), current_ts);
+
+    emit!(TransactionCreateEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        proposer: ctx.accounts.proposer.key(),
+        proposer_record: None,
+        instructions,
+        instructions_hash: ctx.accounts.transaction.instructions_hash,
+        eta: NO_ETA,
+        timestamp: current_ts
+    });
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Instruction handler for [smart_wallet::create_proposer_transaction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::create_proposer_transaction].
+pub fn handler(
+    ctx: Context<CreateProposerTransaction>,
+    instructions: Vec<TXInstruction>,
+    eta: i64,
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    ctx.accounts.smart_wallet.check_eta(eta, current_ts)?;
+
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    let index = smart_wallet.num_transactions;
+    smart_wallet.num_transactions = unwrap_int!(smart_wallet.num_transactions.checked_add(1));
+
+    // allowed proposers are not owners, so the transaction starts with zero approvals
+    let mut signers = Vec::new();
+    signers.resize(smart_wallet.owners.len(), false);
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.smart_wallet = smart_wallet.key();
+    tx.index = index;
+    tx.bump = *unwrap_int!(ctx.bumps.get("transaction"));
+
+    tx.proposer = ctx.accounts.proposer.key();
+    tx.payer = ctx.accounts.payer.key();
+    tx.instructions = instructions.clone();
+    tx.signers = signers.clone();
+    tx.rejections = vec![false; signers.len()];
+    tx.revoked = vec![false; signers.len()];
+    tx.owner_set_seqno = smart_wallet.owner_set_seqno;
+    tx.eta = eta;
+
+    tx.executor = Pubkey::default();
+    tx.executed_at = -1;
+    tx.created_at = current_ts;
+    tx.instructions_hash = tx.compute_instructions_hash()?;
+
+    emit!(TransactionCreateEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        proposer: ctx.accounts.proposer.key(),
+        proposer_record: Some(ctx.accounts.proposer_record.key()),
+        instructions,
+        instructions_hash: ctx.accounts.transaction.instructions_hash,
+        eta,
+        timestamp: current_ts
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateProposerTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.proposer_record.smart_wallet);
+        assert_keys_eq!(self.proposer, self.proposer_record.proposer);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::create_proposer_transaction].
+#[derive(Accounts)]
+#[instruction(bump: u8, instructions: Vec<TXInstruction>)]
+pub struct CreateProposerTransaction<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Transaction].
+    #[account(
+        init,
+        seeds = [
+            b"GokiTransaction".as_ref(),
+            smart_wallet.key().to_bytes().as_ref(),
+            smart_wallet.num_transactions.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Transaction::space(instructions, smart_wallet.owners.len()),
+    )]
+    pub transaction: Account<'info, Transaction>,
+    /// The [ProposerRecord] of the proposer.
+    pub proposer_record: Account<'info, ProposerRecord>,
+    /// The allowed proposer.
+    pub proposer: Signer<'info>,
+    /// Payer to create the [Transaction].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
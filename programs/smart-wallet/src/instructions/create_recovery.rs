@@ -0,0 +1,85 @@
+//! Instruction handler for [smart_wallet::create_recovery].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::create_recovery].
+pub fn handler(
+    ctx: Context<CreateRecovery>,
+    recovery_keys: Vec<Pubkey>,
+    threshold: u64,
+    delay: i64,
+) -> Result<()> {
+    validate_recovery_config(&recovery_keys, threshold, delay)?;
+
+    let recovery = &mut ctx.accounts.recovery;
+    recovery.smart_wallet = ctx.accounts.smart_wallet.key();
+    recovery.bump = *unwrap_int!(ctx.bumps.get("recovery"));
+    recovery.threshold = threshold;
+    recovery.delay = delay;
+    recovery.recovery_keys = recovery_keys.clone();
+
+    emit!(RecoverySetEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        recovery: ctx.accounts.recovery.key(),
+        recovery_keys,
+        threshold,
+        delay,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+/// Validates a set of recovery keys, their threshold, and the recovery delay.
+pub(crate) fn validate_recovery_config(
+    recovery_keys: &[Pubkey],
+    threshold: u64,
+    delay: i64,
+) -> Result<()> {
+    for (i, key) in recovery_keys.iter().enumerate() {
+        invariant!(!recovery_keys[..i].contains(key), InvalidRecoveryConfig);
+    }
+    invariant!(threshold > 0, InvalidRecoveryConfig);
+    invariant!(
+        threshold <= recovery_keys.len() as u64,
+        InvalidRecoveryConfig
+    );
+    invariant!(delay > 0, InvalidRecoveryConfig);
+    invariant!(delay <= MAX_DELAY_SECONDS, DelayTooHigh);
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateRecovery<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smart_wallet.to_account_info().is_signer,
+            "smart_wallet.is_signer"
+        );
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::create_recovery].
+#[derive(Accounts)]
+#[instruction(max_recovery_keys: u8, max_owners: u8)]
+pub struct CreateRecovery<'info> {
+    /// The [SmartWallet].
+    #[account(signer)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Recovery] to create.
+    #[account(
+        init,
+        seeds = [
+            b"GokiRecovery".as_ref(),
+            smart_wallet.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Recovery::space(max_recovery_keys, max_owners)
+    )]
+    pub recovery: Account<'info, Recovery>,
+    /// Payer to create the [Recovery].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,99 @@
+//! Instruction handler for [smart_wallet::create_transaction_with_nonce].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::create_transaction_with_nonce].
+pub fn handler(
+    ctx: Context<CreateTransactionWithNonce>,
+    instructions: Vec<TXInstruction>,
+    eta: i64,
+) -> Result<()> {
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    let owner_index = smart_wallet
+        .try_owner_index_with_permission(ctx.accounts.proposer.key(), PERMISSION_PROPOSE)?;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    smart_wallet.check_eta(eta, current_ts)?;
+
+    let mut signers = Vec::new();
+    signers.resize(smart_wallet.owners.len(), false);
+    signers[owner_index] = smart_wallet.has_permission(owner_index, PERMISSION_APPROVE);
+
+    // the index is still assigned in creation order, but is not part of the address
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    let index = smart_wallet.num_transactions;
+    smart_wallet.num_transactions = unwrap_int!(smart_wallet.num_transactions.checked_add(1));
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.smart_wallet = smart_wallet.key();
+    tx.index = index;
+    tx.bump = *unwrap_int!(ctx.bumps.get("transaction"));
+
+    tx.proposer = ctx.accounts.proposer.key();
+    tx.payer = ctx.accounts.payer.key();
+    tx.instructions = instructions.clone();
+    tx.signers = signers.clone();
+    tx.rejections = vec![false; signers.len()];
+    tx.revoked = vec![false; signers.len()];
+    tx.owner_set_seqno = smart_wallet.owner_set_seqno;
+    tx.eta = eta;
+
+    tx.executor = Pubkey::default();
+    tx.executed_at = -1;
+    tx.created_at = current_ts;
+    tx.instructions_hash = tx.compute_instructions_hash()?;
+    tx.update_threshold_reached_at(&ctx.accounts.smart_wallet, current_ts)?;
+
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.proposer.key(), current_ts);
+
+    emit!(TransactionCreateEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        proposer: ctx.accounts.proposer.key(),
+        proposer_record: None,
+        instructions,
+        instructions_hash: ctx.accounts.transaction.instructions_hash,
+        eta,
+        timestamp: current_ts
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CreateTransactionWithNonce<'info> {
+    fn validate(&self) -> Result<()> {
+        // owner_index check happens in the handler
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::create_transaction_with_nonce].
+#[derive(Accounts)]
+#[instruction(bump: u8, nonce: u64, instructions: Vec<TXInstruction>)]
+pub struct CreateTransactionWithNonce<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Transaction].
+    #[account(
+        init,
+        seeds = [
+            b"GokiTransaction".as_ref(),
+            smart_wallet.key().to_bytes().as_ref(),
+            proposer.key().to_bytes().as_ref(),
+            nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Transaction::space(instructions, smart_wallet.owners.len()),
+    )]
+    pub transaction: Account<'info, Transaction>,
+    /// One of the owners. Checked in the handler via [SmartWallet::try_owner_index_with_permission].
+    pub proposer: Signer<'info>,
+    /// Payer to create the [Transaction].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
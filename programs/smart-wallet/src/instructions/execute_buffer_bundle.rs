@@ -0,0 +1,129 @@
+//! Instruction handler for [smart_wallet::execute_buffer_bundle].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::execute_buffer_bundle].
+pub fn handler(ctx: Context<ExecuteBufferBundle>, bundle_index: u8) -> Result<()> {
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    let wallet_seeds: &[&[&[u8]]] = &[&[
+        b"GokiSmartWallet" as &[u8],
+        &smart_wallet.base.to_bytes(),
+        &[smart_wallet.bump],
+    ]];
+
+    let bundle_index = bundle_index as usize;
+    let bundle = unwrap_opt!(
+        ctx.accounts.buffer.bundles.get(bundle_index),
+        BufferBundleNotFound
+    );
+    invariant!(!bundle.is_executed(), BufferBundleExecuted);
+
+    for ix in bundle.instructions.iter() {
+        solana_program::program::invoke_signed(&(ix).into(), ctx.remaining_accounts, wallet_seeds)?;
+    }
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    let bundle = &mut ctx.accounts.buffer.bundles[bundle_index];
+    bundle.executor = ctx.accounts.owner.key();
+    bundle.executed_at = current_ts;
+
+    // The executed instructions may have modified the smart wallet.
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.reload()?;
+    // the executed instructions were approved by the owners.
+    smart_wallet.last_activity_ts = current_ts;
+
+    emit!(TransactionBufferExecuteEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        buffer: ctx.accounts.buffer.key(),
+        bundle_index: bundle_index as u8,
+        executor: ctx.accounts.owner.key(),
+        timestamp: current_ts
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ExecuteBufferBundle<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.buffer.smart_wallet, "smart_wallet");
+        invariant!(self.smart_wallet.is_migrated(), WalletNotMigrated);
+        invariant!(
+            self.smart_wallet.owner_set_seqno == self.buffer.owner_set_seqno,
+            OwnerSetChanged
+        );
+        invariant!(self.buffer.is_finalized(), BufferBundleNotFinalized);
+        invariant!(!self.buffer.is_vetoed(), TransactionVetoed);
+
+        let eta = self.buffer.eta;
+        let current_ts = Clock::get()?.unix_timestamp;
+        // Has the buffer surpassed timelock?
+        invariant!(current_ts >= eta, TransactionNotReady);
+        if eta != NO_ETA {
+            // Has grace period passed?
+            invariant!(
+                current_ts <= unwrap_int!(eta.checked_add(self.smart_wallet.grace_period)),
+                TransactionIsStale
+            );
+        }
+
+        // Does the ETA still satisfy the timelock?
+        if self.smart_wallet.minimum_delay != 0 {
+            match self.smart_wallet.timelock_mode {
+                TimelockMode::Creation => {
+                    invariant!(
+                        eta >= unwrap_int!(self
+                            .buffer
+                            .created_at
+                            .checked_add(self.smart_wallet.minimum_delay)),
+                        InvalidETA
+                    );
+                }
+                TimelockMode::ThresholdReached => {
+                    let threshold_reached_at = unwrap_opt!(
+                        self.smart_wallet.threshold_reached_at(
+                            &self.buffer.signers,
+                            self.buffer.created_at,
+                            self.buffer.threshold_reached_at,
+                            current_ts
+                        )?,
+                        NotEnoughSigners
+                    );
+                    invariant!(
+                        current_ts
+                            >= unwrap_int!(
+                                threshold_reached_at.checked_add(self.smart_wallet.minimum_delay)
+                            ),
+                        TransactionNotReady
+                    );
+                }
+            }
+        }
+
+        // Do we have enough signers to execute the buffer?
+        let approval_weight = self.smart_wallet.approval_weight(&self.buffer.signers)?;
+        let threshold = self
+            .smart_wallet
+            .effective_threshold(self.buffer.created_at, current_ts)?;
+        invariant!(approval_weight >= threshold, NotEnoughSigners);
+
+        invariant!(!self.smart_wallet.is_paused(), WalletPaused);
+
+        // ensure that the executor is allowed to execute
+        self.smart_wallet.check_executor(self.owner.key())?;
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::execute_buffer_bundle].
+#[derive(Accounts)]
+pub struct ExecuteBufferBundle<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [TransactionBuffer] to execute a bundle of.
+    #[account(mut)]
+    pub buffer: Account<'info, TransactionBuffer>,
+    /// The executor, which must be allowed by [SmartWallet::execution_policy].
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,70 @@
+//! Instruction handler for [smart_wallet::finalize_transaction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::finalize_transaction].
+pub fn handler(ctx: Context<FinalizeTransaction>, eta: i64) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    // the timelock starts counting from finalization, as the instructions may have changed
+    ctx.accounts.smart_wallet.check_eta(eta, current_ts)?;
+
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    let owner_index = smart_wallet
+        .try_owner_index_with_permission(ctx.accounts.proposer.key(), PERMISSION_PROPOSE)?;
+    let mut signers = Vec::new();
+    signers.resize(smart_wallet.owners.len(), false);
+    signers[owner_index] = smart_wallet.has_permission(owner_index, PERMISSION_APPROVE);
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.signers = signers.clone();
+    tx.rejections = vec![false; signers.len()];
+    tx.revoked = vec![false; signers.len()];
+    tx.owner_set_seqno = smart_wallet.owner_set_seqno;
+    tx.eta = eta;
+    // the hash covers the ETA, so approvals must be made against the final ETA
+    tx.instructions_hash = tx.compute_instructions_hash()?;
+    tx.created_at = current_ts;
+    tx.draft = false;
+    tx.update_threshold_reached_at(smart_wallet, current_ts)?;
+
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.proposer.key(), current_ts);
+
+    emit!(TransactionFinalizeEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        instructions_hash: ctx.accounts.transaction.instructions_hash,
+        eta,
+        timestamp: current_ts
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for FinalizeTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.transaction.smart_wallet);
+        assert_keys_eq!(self.proposer, self.transaction.proposer, "proposer");
+        invariant!(self.transaction.draft, TransactionNotDraft);
+
+        // the account is only sized for the owner set of the last edit.
+        invariant!(
+            self.smart_wallet.owner_set_seqno == self.transaction.owner_set_seqno,
+            OwnerSetChanged
+        );
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::finalize_transaction].
+#[derive(Accounts)]
+pub struct FinalizeTransaction<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The draft [Transaction].
+    #[account(mut, has_one = smart_wallet)]
+    pub transaction: Account<'info, Transaction>,
+    /// The proposer of the [Transaction].
+    pub proposer: Signer<'info>,
+}
@@ -0,0 +1,20 @@
+//! Instruction handler for [smart_wallet::finalize_transaction_buffer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::finalize_transaction_buffer].
+pub fn handler(ctx: Context<WriteTransactionBuffer>) -> Result<()> {
+    let buffer = &mut ctx.accounts.buffer;
+    invariant!(!buffer.bundles.is_empty(), BufferBundleNotFound);
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    buffer.finalized_at = current_ts;
+
+    emit!(TransactionBufferFinalizeEvent {
+        smart_wallet: buffer.smart_wallet,
+        buffer: buffer.key(),
+        num_bundles: unwrap_int!(u8::try_from(buffer.bundles.len()).ok()),
+        timestamp: current_ts
+    });
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Instruction handler for [smart_wallet::init_transaction_buffer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::init_transaction_buffer].
+pub fn handler(ctx: Context<InitTransactionBuffer>, eta: i64) -> Result<()> {
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    let owner_index = smart_wallet
+        .try_owner_index_with_permission(ctx.accounts.proposer.key(), PERMISSION_PROPOSE)?;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    smart_wallet.check_eta(eta, current_ts)?;
+
+    let mut signers = Vec::new();
+    signers.resize(smart_wallet.owners.len(), false);
+    signers[owner_index] = smart_wallet.has_permission(owner_index, PERMISSION_APPROVE);
+
+    let buffer = &mut ctx.accounts.buffer;
+    buffer.smart_wallet = smart_wallet.key();
+    buffer.proposer = ctx.accounts.proposer.key();
+    buffer.owner_set_seqno = smart_wallet.owner_set_seqno;
+    buffer.eta = eta;
+    buffer.created_at = current_ts;
+    buffer.finalized_at = -1;
+    buffer.signers = signers;
+    buffer.bundles = Vec::new();
+
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.proposer.key(), current_ts);
+
+    emit!(TransactionBufferInitEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        buffer: ctx.accounts.buffer.key(),
+        proposer: ctx.accounts.proposer.key(),
+        eta,
+        timestamp: current_ts
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for InitTransactionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        // owner_index check happens in the handler
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::init_transaction_buffer].
+#[derive(Accounts)]
+pub struct InitTransactionBuffer<'info> {
+    /// The [SmartWallet].
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [TransactionBuffer], allocated by the client.
+    #[account(zero)]
+    pub buffer: Account<'info, TransactionBuffer>,
+    /// One of the owners. Checked in the handler via [SmartWallet::try_owner_index_with_permission].
+    pub proposer: Signer<'info>,
+}
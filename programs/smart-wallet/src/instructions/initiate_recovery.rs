@@ -0,0 +1,63 @@
+//! Instruction handler for [smart_wallet::initiate_recovery].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::initiate_recovery].
+pub fn handler(
+    ctx: Context<InitiateRecovery>,
+    new_owners: Vec<Pubkey>,
+    new_threshold: u64,
+) -> Result<()> {
+    let recovery = &ctx.accounts.recovery;
+    let num_signed = validators::count_signers(
+        &recovery.recovery_keys,
+        ctx.accounts.initiator.key(),
+        ctx.remaining_accounts,
+    );
+    invariant!(
+        num_signed as u64 >= recovery.threshold,
+        RecoveryNotAuthorized
+    );
+
+    validators::validate_owner_set(&new_owners, &[], &[], new_threshold)?;
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    let max_owners = SmartWallet::max_owners(smart_wallet.to_account_info().data_len());
+    invariant!(new_owners.len() <= max_owners, TooManyOwners);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let recovery = &mut ctx.accounts.recovery;
+    recovery.initiated_at = timestamp;
+    recovery.new_owners = new_owners.clone();
+    recovery.new_threshold = new_threshold;
+
+    emit!(RecoveryInitiateEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        recovery: ctx.accounts.recovery.key(),
+        new_owners,
+        new_threshold,
+        completes_at: unwrap_int!(timestamp.checked_add(ctx.accounts.recovery.delay)),
+        timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for InitiateRecovery<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.recovery.smart_wallet);
+        invariant!(!self.recovery.is_pending(), RecoveryAlreadyPending);
+        // recovery key signatures are checked in the handler.
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::initiate_recovery].
+#[derive(Accounts)]
+pub struct InitiateRecovery<'info> {
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Recovery] of the [SmartWallet].
+    #[account(mut)]
+    pub recovery: Account<'info, Recovery>,
+    /// One of the recovery keys.
+    pub initiator: Signer<'info>,
+}
@@ -0,0 +1,39 @@
+//! Instruction handler for [smart_wallet::migrate_smart_wallet].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::migrate_smart_wallet].
+pub fn handler(ctx: Context<MigrateSmartWallet>) -> Result<()> {
+    let smart_wallet_info = ctx.accounts.smart_wallet.to_account_info();
+    let mut data = smart_wallet_info.try_borrow_mut_data()?;
+    let (_, legacy_len) = LegacySmartWallet::try_from_account_data(&data)?;
+
+    // stale owner keys may follow the reserved space.
+    data[legacy_len..].fill(0);
+
+    let mut smart_wallet = SmartWallet::try_deserialize(&mut &data[..])?;
+    smart_wallet.layout_version = SMART_WALLET_LAYOUT_VERSION;
+    smart_wallet.try_serialize(&mut &mut data[..])?;
+
+    emit!(WalletMigrateEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        layout_version: SMART_WALLET_LAYOUT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateSmartWallet<'info> {
+    fn validate(&self) -> Result<()> {
+        // anyone may migrate a smart wallet
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::migrate_smart_wallet].
+#[derive(Accounts)]
+pub struct MigrateSmartWallet<'info> {
+    /// CHECK: The [SmartWallet] to migrate, which is checked to use the legacy layout.
+    #[account(mut, owner = crate::ID)]
+    pub smart_wallet: UncheckedAccount<'info>,
+}
@@ -1,5 +1,79 @@
+pub mod add_proposer;
+pub mod append_draft_instruction;
 pub mod approve;
+pub mod approve_transaction_buffer;
+pub mod approve_with_hash;
+pub mod approve_with_signatures;
+pub mod cancel_recovery;
+pub mod cancel_transaction;
+pub mod claim_inactive_wallet;
+pub mod close_legacy_transaction;
+pub mod close_transaction;
+pub mod close_transaction_buffer;
+pub mod complete_recovery;
+pub mod create_beneficiary;
+pub mod create_draft_transaction;
+pub mod create_proposer_transaction;
+pub mod create_recovery;
+pub mod create_transaction_with_nonce;
+pub mod execute_buffer_bundle;
+pub mod finalize_transaction;
+pub mod finalize_transaction_buffer;
+pub mod init_transaction_buffer;
+pub mod initiate_recovery;
+pub mod migrate_smart_wallet;
+pub mod pause;
+pub mod reject;
+pub mod remove_beneficiary;
+pub mod remove_draft_instruction;
+pub mod remove_proposer;
+pub mod replace_draft_instruction;
+pub mod resize_smart_wallet;
+pub mod set_beneficiary;
+pub mod set_execution_policy;
+pub mod set_recovery;
 pub mod unapprove;
+pub mod unapprove_transaction_buffer;
+pub mod veto_transaction;
+pub mod veto_transaction_buffer;
+pub mod write_transaction_buffer;
 
+pub use add_proposer::*;
+pub use append_draft_instruction::*;
 pub use approve::*;
+pub use approve_transaction_buffer::*;
+pub use approve_with_hash::*;
+pub use approve_with_signatures::*;
+pub use cancel_recovery::*;
+pub use cancel_transaction::*;
+pub use claim_inactive_wallet::*;
+pub use close_legacy_transaction::*;
+pub use close_transaction::*;
+pub use close_transaction_buffer::*;
+pub use complete_recovery::*;
+pub use create_beneficiary::*;
+pub use create_draft_transaction::*;
+pub use create_proposer_transaction::*;
+pub use create_recovery::*;
+pub use create_transaction_with_nonce::*;
+pub use execute_buffer_bundle::*;
+pub use finalize_transaction::*;
+pub use finalize_transaction_buffer::*;
+pub use init_transaction_buffer::*;
+pub use initiate_recovery::*;
+pub use migrate_smart_wallet::*;
+pub use pause::*;
+pub use reject::*;
+pub use remove_beneficiary::*;
+pub use remove_draft_instruction::*;
+pub use remove_proposer::*;
+pub use replace_draft_instruction::*;
+pub use resize_smart_wallet::*;
+pub use set_beneficiary::*;
+pub use set_execution_policy::*;
+pub use set_recovery::*;
 pub use unapprove::*;
+pub use unapprove_transaction_buffer::*;
+pub use veto_transaction::*;
+pub use veto_transaction_buffer::*;
+pub use write_transaction_buffer::*;
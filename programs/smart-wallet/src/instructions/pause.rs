@@ -0,0 +1,60 @@
+//! Instruction handler for [smart_wallet::pause].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::pause].
+pub fn handler(ctx: Context<Pause>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.paused_at = timestamp;
+
+    emit!(WalletPauseEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp
+    });
+    Ok(())
+}
+
+/// Checks that the pause is signed by the pause authority, or by at least
+/// [SmartWallet::pause_threshold] owners.
+///
+/// Owners other than the `authority` sign as remaining accounts.
+pub(crate) fn validate_pause_signers(ctx: &Context<Pause>) -> Result<()> {
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    invariant!(!smart_wallet.is_paused(), WalletPaused);
+
+    let authority = ctx.accounts.authority.key();
+    if smart_wallet.pause_authority != Pubkey::default()
+        && authority == smart_wallet.pause_authority
+    {
+        return Ok(());
+    }
+
+    invariant!(smart_wallet.pause_threshold > 0, PauseNotAuthorized);
+    // owners may have been removed since the pause threshold was set.
+    let pause_threshold = smart_wallet
+        .pause_threshold
+        .min(smart_wallet.owners.len() as u64);
+    let num_signed =
+        validators::count_signers(&smart_wallet.owners, authority, ctx.remaining_accounts);
+    invariant!(num_signed as u64 >= pause_threshold, PauseNotAuthorized);
+    Ok(())
+}
+
+impl<'info> Validate<'info> for Pause<'info> {
+    fn validate(&self) -> Result<()> {
+        // signers are checked in the handler via [validate_pause_signers].
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::pause].
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// The [SmartWallet] to pause.
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [SmartWallet::pause_authority], or one of the owners.
+    pub authority: Signer<'info>,
+}
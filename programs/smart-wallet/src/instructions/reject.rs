@@ -0,0 +1,39 @@
+//! Instruction handler for [smart_wallet::reject].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::reject].
+pub fn handler(ctx: Context<Approve>) -> Result<()> {
+    let smart_wallet = &ctx.accounts.smart_wallet;
+    let owner_index = smart_wallet
+        .try_owner_index_with_permission(ctx.accounts.owner.key(), PERMISSION_APPROVE)?;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.signers[owner_index] = false;
+    tx.rejections[owner_index] = true;
+    tx.revoked[owner_index] = true;
+    tx.update_threshold_reached_at(smart_wallet, current_ts)?;
+
+    // the weight of all owners which have not rejected is the most the
+    // transaction could ever be approved with
+    let not_rejected: Vec<bool> = tx.rejections.iter().map(|rejected| !rejected).collect();
+    let reachable_weight = smart_wallet.approval_weight(&not_rejected)?;
+    let rejected = reachable_weight < smart_wallet.minimum_threshold();
+    if rejected {
+        tx.rejected_at = current_ts;
+    }
+
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.owner.key(), current_ts);
+
+    emit!(TransactionRejectEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        owner: ctx.accounts.owner.key(),
+        rejected,
+        timestamp: current_ts
+    });
+    Ok(())
+}
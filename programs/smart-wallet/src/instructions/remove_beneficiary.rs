@@ -0,0 +1,38 @@
+//! Instruction handler for [smart_wallet::remove_beneficiary].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::remove_beneficiary].
+pub fn handler(ctx: Context<RemoveBeneficiary>) -> Result<()> {
+    emit!(BeneficiaryRemoveEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for RemoveBeneficiary<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smart_wallet.to_account_info().is_signer,
+            "smart_wallet.is_signer"
+        );
+        assert_keys_eq!(self.smart_wallet, self.beneficiary.smart_wallet);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::remove_beneficiary].
+#[derive(Accounts)]
+pub struct RemoveBeneficiary<'info> {
+    /// The [SmartWallet].
+    #[account(signer)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Beneficiary] to close.
+    #[account(mut, close = receiver)]
+    pub beneficiary: Account<'info, Beneficiary>,
+    /// CHECK: Receives the rent of the closed [Beneficiary].
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
@@ -0,0 +1,39 @@
+//! Instruction handler for [smart_wallet::remove_proposer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::remove_proposer].
+pub fn handler(ctx: Context<RemoveProposer>) -> Result<()> {
+    emit!(WalletRemoveProposerEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        proposer: ctx.accounts.proposer_record.proposer,
+        proposer_record: ctx.accounts.proposer_record.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for RemoveProposer<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smart_wallet.to_account_info().is_signer,
+            "smart_wallet.is_signer"
+        );
+        assert_keys_eq!(self.smart_wallet, self.proposer_record.smart_wallet);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::remove_proposer].
+#[derive(Accounts)]
+pub struct RemoveProposer<'info> {
+    /// The [SmartWallet].
+    #[account(signer)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [ProposerRecord] to close.
+    #[account(mut, close = receiver)]
+    pub proposer_record: Account<'info, ProposerRecord>,
+    /// CHECK: Receives the rent of the closed [ProposerRecord].
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
@@ -0,0 +1,12 @@
+//! Instruction handler for [smart_wallet::replace_draft_instruction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::replace_draft_instruction].
+pub fn handler(ctx: Context<EditDraftTransaction>, index: u8, ix: TXInstruction) -> Result<()> {
+    let mut instructions = ctx.accounts.transaction.instructions.clone();
+    let index = index as usize;
+    invariant!(index < instructions.len(), "instruction index out of range");
+    instructions[index] = ix;
+    ctx.accounts.set_instructions(instructions)
+}
@@ -0,0 +1,58 @@
+//! Instruction handler for [smart_wallet::resize_smart_wallet].
+
+use crate::*;
+use anchor_lang::system_program;
+
+/// Instruction handler for [smart_wallet::resize_smart_wallet].
+pub fn handler(ctx: Context<ResizeSmartWallet>, max_owners: u8) -> Result<()> {
+    let smart_wallet_info = ctx.accounts.smart_wallet.to_account_info();
+    let new_space = SmartWallet::space(max_owners);
+    invariant!(
+        new_space > smart_wallet_info.data_len(),
+        "max_owners must be greater than the current capacity"
+    );
+
+    let required_lamports = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(smart_wallet_info.lamports());
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: smart_wallet_info.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    smart_wallet_info.realloc(new_space, true)?;
+
+    emit!(WalletResizeEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        max_owners,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ResizeSmartWallet<'info> {
+    fn validate(&self) -> Result<()> {
+        // anyone may pay to grow a smart wallet
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::resize_smart_wallet].
+#[derive(Accounts)]
+pub struct ResizeSmartWallet<'info> {
+    /// The [SmartWallet] to resize.
+    #[account(mut)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
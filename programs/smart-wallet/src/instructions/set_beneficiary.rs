@@ -0,0 +1,55 @@
+//! Instruction handler for [smart_wallet::set_beneficiary].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::set_beneficiary].
+pub fn handler(
+    ctx: Context<SetBeneficiary>,
+    owners: Vec<Pubkey>,
+    threshold: u64,
+    inactivity_period: i64,
+) -> Result<()> {
+    validators::validate_owner_set(&owners, &[], &[], threshold)?;
+    invariant!(inactivity_period > 0, "inactivity period must be positive");
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    beneficiary.inactivity_period = inactivity_period;
+    beneficiary.threshold = threshold;
+    beneficiary.owners = owners.clone();
+
+    // the inactivity period counts from when the beneficiary is configured.
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.smart_wallet.last_activity_ts = timestamp;
+
+    emit!(BeneficiarySetEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        owners,
+        threshold,
+        inactivity_period,
+        timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetBeneficiary<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smart_wallet.to_account_info().is_signer,
+            "smart_wallet.is_signer"
+        );
+        assert_keys_eq!(self.smart_wallet, self.beneficiary.smart_wallet);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::set_beneficiary].
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    /// The [SmartWallet].
+    #[account(mut, signer)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Beneficiary] of the [SmartWallet].
+    #[account(mut)]
+    pub beneficiary: Account<'info, Beneficiary>,
+}
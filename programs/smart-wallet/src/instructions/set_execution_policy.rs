@@ -0,0 +1,31 @@
+//! Instruction handler for [smart_wallet::set_execution_policy].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::set_execution_policy].
+pub fn handler(
+    ctx: Context<Auth>,
+    execution_policy: ExecutionPolicy,
+    keepers: Vec<Pubkey>,
+) -> Result<()> {
+    invariant!(
+        execution_policy == ExecutionPolicy::Keepers || keepers.is_empty(),
+        InvalidKeepers
+    );
+    invariant!(keepers.len() <= MAX_KEEPERS, InvalidKeepers);
+    for (index, keeper) in keepers.iter().enumerate() {
+        invariant!(!keepers[..index].contains(keeper), InvalidKeepers);
+    }
+
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.execution_policy = execution_policy;
+    smart_wallet.keepers = keepers.clone();
+
+    emit!(WalletSetExecutionPolicyEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        execution_policy,
+        keepers,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,54 @@
+//! Instruction handler for [smart_wallet::set_recovery].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::set_recovery].
+pub fn handler(
+    ctx: Context<SetRecovery>,
+    recovery_keys: Vec<Pubkey>,
+    threshold: u64,
+    delay: i64,
+) -> Result<()> {
+    create_recovery::validate_recovery_config(&recovery_keys, threshold, delay)?;
+
+    // changing the recovery keys discards any pending recovery.
+    let recovery = &mut ctx.accounts.recovery;
+    recovery.threshold = threshold;
+    recovery.delay = delay;
+    recovery.recovery_keys = recovery_keys.clone();
+    recovery.initiated_at = 0;
+    recovery.new_owners = vec![];
+    recovery.new_threshold = 0;
+
+    emit!(RecoverySetEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        recovery: ctx.accounts.recovery.key(),
+        recovery_keys,
+        threshold,
+        delay,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for SetRecovery<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smart_wallet.to_account_info().is_signer,
+            "smart_wallet.is_signer"
+        );
+        assert_keys_eq!(self.smart_wallet, self.recovery.smart_wallet);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::set_recovery].
+#[derive(Accounts)]
+pub struct SetRecovery<'info> {
+    /// The [SmartWallet].
+    #[account(signer)]
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Recovery] of the [SmartWallet].
+    #[account(mut)]
+    pub recovery: Account<'info, Recovery>,
+}
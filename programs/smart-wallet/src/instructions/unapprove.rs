@@ -8,13 +8,23 @@ pub fn handler(ctx: Context<Approve>) -> Result<()> {
         .accounts
         .smart_wallet
         .try_owner_index(ctx.accounts.owner.key())?;
-    ctx.accounts.transaction.signers[owner_index] = false;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.signers[owner_index] = false;
+    // prior off-chain approvals of the owner may no longer be submitted
+    tx.revoked[owner_index] = true;
+    tx.update_threshold_reached_at(&ctx.accounts.smart_wallet, current_ts)?;
+
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.owner.key(), current_ts);
 
     emit!(TransactionUnapproveEvent {
         smart_wallet: ctx.accounts.smart_wallet.key(),
         transaction: ctx.accounts.transaction.key(),
         owner: ctx.accounts.owner.key(),
-        timestamp: Clock::get()?.unix_timestamp
+        timestamp: current_ts
     });
     Ok(())
 }
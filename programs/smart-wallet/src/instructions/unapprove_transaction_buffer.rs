@@ -0,0 +1,28 @@
+//! Instruction handler for [smart_wallet::unapprove_transaction_buffer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::unapprove_transaction_buffer].
+pub fn handler(ctx: Context<ApproveTransactionBuffer>) -> Result<()> {
+    let owner_index = ctx
+        .accounts
+        .smart_wallet
+        .try_owner_index(ctx.accounts.owner.key())?;
+    ctx.accounts.buffer.signers[owner_index] = false;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .buffer
+        .update_threshold_reached_at(&ctx.accounts.smart_wallet, current_ts)?;
+    ctx.accounts
+        .smart_wallet
+        .record_owner_activity(ctx.accounts.owner.key(), current_ts);
+
+    emit!(TransactionBufferUnapproveEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        buffer: ctx.accounts.buffer.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: current_ts
+    });
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Instruction handler for [smart_wallet::veto_transaction].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::veto_transaction].
+pub fn handler(ctx: Context<VetoTransaction>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let tx = &mut ctx.accounts.transaction;
+    tx.vetoer = ctx.accounts.guardian.key();
+    tx.vetoed_at = timestamp;
+
+    emit!(TransactionVetoEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        guardian: ctx.accounts.guardian.key(),
+        timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for VetoTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.transaction.smart_wallet);
+        assert_keys_neq!(self.smart_wallet.guardian, Pubkey::default(), NoGuardian);
+        assert_keys_eq!(self.guardian, self.smart_wallet.guardian, "guardian");
+
+        invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+        invariant!(!self.transaction.is_vetoed(), TransactionVetoed);
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::veto_transaction].
+#[derive(Accounts)]
+pub struct VetoTransaction<'info> {
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Transaction] to veto.
+    #[account(mut)]
+    pub transaction: Account<'info, Transaction>,
+    /// The [SmartWallet::guardian].
+    pub guardian: Signer<'info>,
+}
@@ -0,0 +1,44 @@
+//! Instruction handler for [smart_wallet::veto_transaction_buffer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::veto_transaction_buffer].
+pub fn handler(ctx: Context<VetoTransactionBuffer>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let buffer = &mut ctx.accounts.buffer;
+    buffer.vetoer = ctx.accounts.guardian.key();
+    buffer.vetoed_at = timestamp;
+
+    emit!(TransactionBufferVetoEvent {
+        smart_wallet: ctx.accounts.smart_wallet.key(),
+        buffer: ctx.accounts.buffer.key(),
+        guardian: ctx.accounts.guardian.key(),
+        timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for VetoTransactionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.smart_wallet, self.buffer.smart_wallet);
+        assert_keys_neq!(self.smart_wallet.guardian, Pubkey::default(), NoGuardian);
+        assert_keys_eq!(self.guardian, self.smart_wallet.guardian, "guardian");
+
+        invariant!(!self.buffer.is_vetoed(), TransactionVetoed);
+
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::veto_transaction_buffer].
+#[derive(Accounts)]
+pub struct VetoTransactionBuffer<'info> {
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [TransactionBuffer] to veto.
+    #[account(mut)]
+    pub buffer: Account<'info, TransactionBuffer>,
+    /// The [SmartWallet::guardian].
+    pub guardian: Signer<'info>,
+}
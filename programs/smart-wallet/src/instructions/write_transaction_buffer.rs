@@ -0,0 +1,53 @@
+//! Instruction handler for [smart_wallet::write_transaction_buffer].
+
+use crate::*;
+
+/// Instruction handler for [smart_wallet::write_transaction_buffer].
+pub fn handler(
+    ctx: Context<WriteTransactionBuffer>,
+    bundle_index: u8,
+    instructions: Vec<TXInstruction>,
+) -> Result<()> {
+    let buffer = &mut ctx.accounts.buffer;
+    let bundle_index = bundle_index as usize;
+    invariant!(bundle_index <= buffer.bundles.len(), BufferBundleOutOfRange);
+
+    if bundle_index == buffer.bundles.len() {
+        // bundles are counted with a u8.
+        invariant!(buffer.bundles.len() < MAX_BUFFER_BUNDLES, BufferFull);
+        buffer.bundles.push(TransactionBundle {
+            executor: Pubkey::default(),
+            executed_at: -1,
+            instructions,
+        });
+    } else {
+        buffer.bundles[bundle_index]
+            .instructions
+            .extend(instructions);
+    }
+
+    // the buffer must still fit in the account allocated by the client.
+    let space = 8 + buffer.try_to_vec()?.len();
+    invariant!(space <= buffer.to_account_info().data_len(), BufferFull);
+
+    Ok(())
+}
+
+/// This validator is used for both writing and finalizing.
+impl<'info> Validate<'info> for WriteTransactionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.proposer, self.buffer.proposer, "proposer");
+        invariant!(!self.buffer.is_finalized(), BufferFinalized);
+        Ok(())
+    }
+}
+
+/// Accounts for [smart_wallet::write_transaction_buffer] and [smart_wallet::finalize_transaction_buffer].
+#[derive(Accounts)]
+pub struct WriteTransactionBuffer<'info> {
+    /// The [TransactionBuffer].
+    #[account(mut)]
+    pub buffer: Account<'info, TransactionBuffer>,
+    /// The proposer of the [TransactionBuffer].
+    pub proposer: Signer<'info>,
+}
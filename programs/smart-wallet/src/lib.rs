@@ -47,6 +47,27 @@ pub const DEFAULT_GRACE_PERIOD: i64 = 14 * SECONDS_PER_DAY;
 /// Constant declaring that there is no ETA of the transaction.
 pub const NO_ETA: i64 = -1;
 
+/// Current version of the layout of [SmartWallet] accounts.
+pub const SMART_WALLET_LAYOUT_VERSION: u64 = 1;
+
+/// Maximum number of [SmartWallet::keepers].
+pub const MAX_KEEPERS: usize = 8;
+
+/// Maximum number of [TransactionBundle]s in a [TransactionBuffer].
+pub const MAX_BUFFER_BUNDLES: usize = u8::MAX as usize;
+
+/// Domain separator of the messages owners sign to approve a [Transaction] off-chain.
+pub const APPROVAL_MESSAGE_DOMAIN: &[u8] = b"GokiSmartWalletApproval";
+
+/// Owner permission to propose [Transaction]s.
+pub const PERMISSION_PROPOSE: u8 = 1 << 0;
+/// Owner permission to approve [Transaction]s.
+pub const PERMISSION_APPROVE: u8 = 1 << 1;
+/// Owner permission to execute [Transaction]s.
+pub const PERMISSION_EXECUTE: u8 = 1 << 2;
+/// All owner permissions.
+pub const PERMISSION_ALL: u8 = PERMISSION_PROPOSE | PERMISSION_APPROVE | PERMISSION_EXECUTE;
+
 declare_id!("GokivDYuQXPZCWRkwMhdH2h91KpDQXBEmpgBgs55bnpH");
 
 #[program]
@@ -57,6 +78,7 @@ pub mod smart_wallet {
 
     /// Initializes a new [SmartWallet] account with a set of owners and a threshold.
     #[access_control(ctx.accounts.validate())]
+    #[allow(clippy::too_many_arguments)]
     pub fn create_smart_wallet(
         ctx: Context<CreateSmartWallet>,
         _bump: u8,
@@ -64,11 +86,18 @@ pub mod smart_wallet {
         owners: Vec<Pubkey>,
         threshold: u64,
         minimum_delay: i64,
+        grace_period: Option<i64>,
+        owner_weights: Vec<u64>,
     ) -> Result<()> {
         invariant!(minimum_delay >= 0, "delay must be positive");
         invariant!(minimum_delay < MAX_DELAY_SECONDS, DelayTooHigh);
 
+        let grace_period = grace_period.unwrap_or(DEFAULT_GRACE_PERIOD);
+        invariant!(grace_period > 0, "grace period must be positive");
+        invariant!(grace_period < MAX_DELAY_SECONDS, DelayTooHigh);
+
         invariant!((max_owners as usize) >= owners.len(), "max_owners");
+        validators::validate_owner_set(&owners, &owner_weights, &[], threshold)?;
 
         let smart_wallet = &mut ctx.accounts.smart_wallet;
         smart_wallet.base = ctx.accounts.base.key();
@@ -76,38 +105,161 @@ pub mod smart_wallet {
 
         smart_wallet.threshold = threshold;
         smart_wallet.minimum_delay = minimum_delay;
-        smart_wallet.grace_period = DEFAULT_GRACE_PERIOD;
+        smart_wallet.grace_period = grace_period;
 
         smart_wallet.owner_set_seqno = 0;
         smart_wallet.num_transactions = 0;
+        smart_wallet.layout_version = SMART_WALLET_LAYOUT_VERSION;
+        smart_wallet.num_closed_transactions = 0;
+        smart_wallet.last_activity_ts = Clock::get()?.unix_timestamp;
 
         smart_wallet.owners = owners.clone();
+        smart_wallet.owner_weights = owner_weights.clone();
 
         emit!(WalletCreateEvent {
             smart_wallet: ctx.accounts.smart_wallet.key(),
             owners,
+            owner_weights,
             threshold,
             minimum_delay,
+            grace_period,
             timestamp: Clock::get()?.unix_timestamp
         });
         Ok(())
     }
 
+    /// Grows the [SmartWallet] account so that it may hold up to `max_owners` owners.
+    /// Anyone may pay for the additional space.
+    #[access_control(ctx.accounts.validate())]
+    pub fn resize_smart_wallet(ctx: Context<ResizeSmartWallet>, max_owners: u8) -> Result<()> {
+        instructions::resize_smart_wallet::handler(ctx, max_owners)
+    }
+
+    /// Migrates a [SmartWallet] created before [SmartWallet::owner_weights] was added
+    /// to the current account layout, clearing any stale data following its reserved space.
+    ///
+    /// [SmartWallet]s must be migrated before they may execute [Transaction]s.
+    /// They usually need to be resized via [smart_wallet::resize_smart_wallet()]
+    /// before owners may be added. Anyone may migrate a [SmartWallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_smart_wallet(ctx: Context<MigrateSmartWallet>) -> Result<()> {
+        instructions::migrate_smart_wallet::handler(ctx)
+    }
+
     /// Sets the owners field on the smart_wallet. The only way this can be invoked
     /// is via a recursive call from execute_transaction -> set_owners.
+    ///
+    /// This resets the weight of every owner to 1 and grants every owner [PERMISSION_ALL].
     #[access_control(ctx.accounts.validate())]
     pub fn set_owners(ctx: Context<Auth>, owners: Vec<Pubkey>) -> Result<()> {
         let smart_wallet = &mut ctx.accounts.smart_wallet;
         if (owners.len() as u64) < smart_wallet.threshold {
             smart_wallet.threshold = owners.len() as u64;
         }
+        validators::validate_owner_set(&owners, &[], &[], smart_wallet.threshold)?;
 
         smart_wallet.owners = owners.clone();
+        smart_wallet.owner_weights = vec![];
+        smart_wallet.owner_permissions = vec![];
         smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
 
         emit!(WalletSetOwnersEvent {
             smart_wallet: ctx.accounts.smart_wallet.key(),
             owners,
+            owner_weights: vec![],
+            owner_permissions: vec![],
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Adds an owner with a weight of 1 and [PERMISSION_ALL] to the smart_wallet.
+    /// The only way this can be invoked is via a recursive call from
+    /// execute_transaction -> add_owner.
+    ///
+    /// Fails if the account has no space for another owner, in which case it
+    /// must first be grown via [smart_wallet::resize_smart_wallet()].
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_owner(ctx: Context<Auth>, owner: Pubkey) -> Result<()> {
+        let max_owners =
+            SmartWallet::max_owners(ctx.accounts.smart_wallet.to_account_info().data_len());
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        invariant!(
+            smart_wallet.owner_index_opt(owner).is_none(),
+            DuplicateOwner
+        );
+        invariant!(smart_wallet.owners.len() < max_owners, TooManyOwners);
+
+        smart_wallet.owners.push(owner);
+        if !smart_wallet.owner_weights.is_empty() {
+            smart_wallet.owner_weights.push(1);
+        }
+        if !smart_wallet.owner_permissions.is_empty() {
+            smart_wallet.owner_permissions.push(PERMISSION_ALL);
+        }
+        smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
+
+        emit!(WalletAddOwnerEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            owner,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Removes an owner from the smart_wallet and sets the new threshold.
+    /// The only way this can be invoked is via a recursive call from
+    /// execute_transaction -> remove_owner.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_owner(ctx: Context<Auth>, owner: Pubkey, threshold: u64) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        let owner_index = smart_wallet.try_owner_index(owner)?;
+        smart_wallet.owners.remove(owner_index);
+        if !smart_wallet.owner_weights.is_empty() {
+            smart_wallet.owner_weights.remove(owner_index);
+        }
+        if !smart_wallet.owner_permissions.is_empty() {
+            smart_wallet.owner_permissions.remove(owner_index);
+        }
+        validators::validate_owner_set(
+            &smart_wallet.owners,
+            &smart_wallet.owner_weights,
+            &smart_wallet.owner_permissions,
+            threshold,
+        )?;
+
+        smart_wallet.threshold = threshold;
+        smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
+
+        emit!(WalletRemoveOwnerEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            owner,
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Replaces an owner of the smart_wallet with a new owner, keeping its position,
+    /// weight, and permissions.
+    /// The only way this can be invoked is via a recursive call from
+    /// execute_transaction -> swap_owner.
+    #[access_control(ctx.accounts.validate())]
+    pub fn swap_owner(ctx: Context<Auth>, old_owner: Pubkey, new_owner: Pubkey) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        let owner_index = smart_wallet.try_owner_index(old_owner)?;
+        invariant!(
+            smart_wallet.owner_index_opt(new_owner).is_none(),
+            DuplicateOwner
+        );
+
+        smart_wallet.owners[owner_index] = new_owner;
+        smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
+
+        emit!(WalletSwapOwnerEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            old_owner,
+            new_owner,
             timestamp: Clock::get()?.unix_timestamp
         });
         Ok(())
@@ -118,11 +270,13 @@ pub mod smart_wallet {
     /// change_threshold.
     #[access_control(ctx.accounts.validate())]
     pub fn change_threshold(ctx: Context<Auth>, threshold: u64) -> Result<()> {
-        invariant!(
-            threshold <= ctx.accounts.smart_wallet.owners.len() as u64,
-            InvalidThreshold
-        );
         let smart_wallet = &mut ctx.accounts.smart_wallet;
+        validators::validate_owner_set(
+            &smart_wallet.owners,
+            &smart_wallet.owner_weights,
+            &smart_wallet.owner_permissions,
+            threshold,
+        )?;
         smart_wallet.threshold = threshold;
 
         emit!(WalletChangeThresholdEvent {
@@ -133,8 +287,338 @@ pub mod smart_wallet {
         Ok(())
     }
 
+    /// Sets the voting weight of each owner of the smart_wallet. The only way this
+    /// can be invoked is via a recursive call from execute_transaction ->
+    /// set_owner_weights.
+    ///
+    /// `owner_weights[index]` is the weight of `owners[index]`. If empty, every
+    /// owner has a weight of 1.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_owner_weights(ctx: Context<Auth>, owner_weights: Vec<u64>) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        validators::validate_owner_set(
+            &smart_wallet.owners,
+            &owner_weights,
+            &smart_wallet.owner_permissions,
+            smart_wallet.threshold,
+        )?;
+        smart_wallet.owner_weights = owner_weights.clone();
+        smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
+
+        emit!(WalletSetOwnerWeightsEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            owner_weights,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Sets the permissions of each owner of the smart_wallet. The only way this
+    /// can be invoked is via a recursive call from execute_transaction ->
+    /// set_owner_permissions.
+    ///
+    /// `owner_permissions[index]` is the permission bitmask of `owners[index]`.
+    /// If empty, every owner has [PERMISSION_ALL].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_owner_permissions(ctx: Context<Auth>, owner_permissions: Vec<u8>) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        validators::validate_owner_set(
+            &smart_wallet.owners,
+            &smart_wallet.owner_weights,
+            &owner_permissions,
+            smart_wallet.threshold,
+        )?;
+        smart_wallet.owner_permissions = owner_permissions.clone();
+        smart_wallet.owner_set_seqno = unwrap_int!(smart_wallet.owner_set_seqno.checked_add(1));
+
+        emit!(WalletSetOwnerPermissionsEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            owner_permissions,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Allows a non-owner to propose transactions to the smart_wallet by creating
+    /// a [ProposerRecord]. The only way this can be invoked is via a recursive call
+    /// from execute_transaction -> add_proposer.
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_proposer(ctx: Context<AddProposer>, proposer: Pubkey) -> Result<()> {
+        instructions::add_proposer::handler(ctx, proposer)
+    }
+
+    /// Revokes a [ProposerRecord]. The only way this can be invoked is via a
+    /// recursive call from execute_transaction -> remove_proposer.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_proposer(ctx: Context<RemoveProposer>) -> Result<()> {
+        instructions::remove_proposer::handler(ctx)
+    }
+
+    /// Sets who may execute approved transactions of the smart_wallet, with up to
+    /// [MAX_KEEPERS] keepers. The only way this can be invoked is via a recursive
+    /// call from execute_transaction -> set_execution_policy.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_execution_policy(
+        ctx: Context<Auth>,
+        execution_policy: ExecutionPolicy,
+        keepers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_execution_policy::handler(ctx, execution_policy, keepers)
+    }
+
+    /// Sets the guardian of the smart_wallet, which may veto any transaction
+    /// which has not been executed. [Pubkey::default] removes the guardian.
+    /// The only way this can be invoked is via a recursive call from
+    /// execute_transaction -> set_guardian.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_guardian(ctx: Context<Auth>, guardian: Pubkey) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        smart_wallet.guardian = guardian;
+
+        emit!(WalletSetGuardianEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            guardian,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Sets the pause authority of the smart_wallet and the number of owners
+    /// which may pause it by signing together. The only way this can be invoked
+    /// is via a recursive call from execute_transaction -> set_pause_authority.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_pause_authority(
+        ctx: Context<Auth>,
+        pause_authority: Pubkey,
+        pause_threshold: u64,
+    ) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        invariant!(
+            pause_threshold <= smart_wallet.owners.len() as u64,
+            "pause_threshold must not exceed the number of owners"
+        );
+        smart_wallet.pause_authority = pause_authority;
+        smart_wallet.pause_threshold = pause_threshold;
+
+        emit!(WalletSetPauseAuthorityEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            pause_authority,
+            pause_threshold,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Pauses the smart_wallet on behalf of the pause authority or
+    /// [SmartWallet::pause_threshold] owners, which sign as remaining accounts.
+    ///
+    /// While paused, transactions and owner invocations cannot be executed,
+    /// except for transactions which [smart_wallet::unpause] the smart_wallet.
+    #[access_control(ctx.accounts.validate())]
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause::validate_pause_signers(&ctx)?;
+        instructions::pause::handler(ctx)
+    }
+
+    /// Unpauses the smart_wallet. The only way this can be invoked is via a
+    /// recursive call from execute_transaction -> unpause.
+    #[access_control(ctx.accounts.validate())]
+    pub fn unpause(ctx: Context<Auth>) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        invariant!(smart_wallet.is_paused(), "smart wallet is not paused");
+        smart_wallet.paused_at = 0;
+
+        emit!(WalletUnpauseEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Creates the [Recovery] of the smart_wallet. The only way this can be
+    /// invoked is via a recursive call from execute_transaction -> create_recovery.
+    ///
+    /// `max_recovery_keys` and `max_owners` bound the size of the recovery keys
+    /// and of the owner sets the [Recovery] can hold.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_recovery(
+        ctx: Context<CreateRecovery>,
+        _max_recovery_keys: u8,
+        _max_owners: u8,
+        recovery_keys: Vec<Pubkey>,
+        threshold: u64,
+        delay: i64,
+    ) -> Result<()> {
+        instructions::create_recovery::handler(ctx, recovery_keys, threshold, delay)
+    }
+
+    /// Changes the recovery keys, threshold, and delay of the [Recovery],
+    /// discarding any pending recovery. The only way this can be invoked is
+    /// via a recursive call from execute_transaction -> set_recovery.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_recovery(
+        ctx: Context<SetRecovery>,
+        recovery_keys: Vec<Pubkey>,
+        threshold: u64,
+        delay: i64,
+    ) -> Result<()> {
+        instructions::set_recovery::handler(ctx, recovery_keys, threshold, delay)
+    }
+
+    /// Initiates the replacement of the owners of the smart_wallet on behalf of
+    /// [Recovery::threshold] recovery keys, which sign as remaining accounts.
+    #[access_control(ctx.accounts.validate())]
+    pub fn initiate_recovery(
+        ctx: Context<InitiateRecovery>,
+        new_owners: Vec<Pubkey>,
+        new_threshold: u64,
+    ) -> Result<()> {
+        instructions::initiate_recovery::handler(ctx, new_owners, new_threshold)
+    }
+
+    /// Cancels a pending recovery on behalf of one of the current owners.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::cancel_recovery::handler(ctx)
+    }
+
+    /// Replaces the owners and threshold of the smart_wallet with those of a
+    /// pending recovery, once the recovery delay has passed. Anyone may
+    /// complete a recovery.
+    #[access_control(ctx.accounts.validate())]
+    pub fn complete_recovery(ctx: Context<CompleteRecovery>) -> Result<()> {
+        instructions::complete_recovery::handler(ctx)
+    }
+
+    /// Registers a [Beneficiary] which may claim the smart_wallet once no owner
+    /// has been active for `inactivity_period` seconds. The only way this can be
+    /// invoked is via a recursive call from execute_transaction -> create_beneficiary.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_beneficiary(
+        ctx: Context<CreateBeneficiary>,
+        _max_owners: u8,
+        owners: Vec<Pubkey>,
+        threshold: u64,
+        inactivity_period: i64,
+    ) -> Result<()> {
+        instructions::create_beneficiary::handler(ctx, owners, threshold, inactivity_period)
+    }
+
+    /// Changes the [Beneficiary] of the smart_wallet. The only way this can be
+    /// invoked is via a recursive call from execute_transaction -> set_beneficiary.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_beneficiary(
+        ctx: Context<SetBeneficiary>,
+        owners: Vec<Pubkey>,
+        threshold: u64,
+        inactivity_period: i64,
+    ) -> Result<()> {
+        instructions::set_beneficiary::handler(ctx, owners, threshold, inactivity_period)
+    }
+
+    /// Removes the [Beneficiary] of the smart_wallet. The only way this can be
+    /// invoked is via a recursive call from execute_transaction -> remove_beneficiary.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_beneficiary(ctx: Context<RemoveBeneficiary>) -> Result<()> {
+        instructions::remove_beneficiary::handler(ctx)
+    }
+
+    /// Replaces the owners and threshold of the smart_wallet with those of its
+    /// [Beneficiary] once no owner has been active for the inactivity period.
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_inactive_wallet(ctx: Context<ClaimInactiveWallet>) -> Result<()> {
+        instructions::claim_inactive_wallet::handler(ctx)
+    }
+
+    /// Sets the threshold decay of the smart_wallet: every `decay_period` seconds
+    /// after a transaction is created, the threshold required to execute it
+    /// decreases by 1, down to `floor`. A `decay_period` of 0 disables the decay.
+    /// The only way this can be invoked is via a recursive call from
+    /// execute_transaction -> set_threshold_decay.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_threshold_decay(ctx: Context<Auth>, decay_period: i64, floor: u64) -> Result<()> {
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        invariant!(decay_period >= 0, "decay period must not be negative");
+        if decay_period > 0 {
+            invariant!(floor > 0, ZeroThreshold);
+            invariant!(floor <= smart_wallet.threshold, InvalidThreshold);
+        }
+        smart_wallet.threshold_decay_period = decay_period;
+        smart_wallet.threshold_floor = floor;
+
+        emit!(WalletSetThresholdDecayEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            decay_period,
+            floor,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Sets when the minimum delay of the smart_wallet starts counting: from the
+    /// creation of a transaction, or from when its approvals reached the threshold.
+    /// The only way this can be invoked is via a recursive call from
+    /// execute_transaction -> set_timelock_mode.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_timelock_mode(ctx: Context<Auth>, timelock_mode: TimelockMode) -> Result<()> {
+        ctx.accounts.smart_wallet.timelock_mode = timelock_mode;
+
+        emit!(WalletSetTimelockModeEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            timelock_mode,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Changes the grace period of the smart_wallet. The only way this can be
+    /// invoked is via a recursive call from execute_transaction ->
+    /// set_grace_period.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_grace_period(ctx: Context<Auth>, grace_period: i64) -> Result<()> {
+        invariant!(grace_period > 0, "grace period must be positive");
+        invariant!(grace_period < MAX_DELAY_SECONDS, DelayTooHigh);
+
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        smart_wallet.grace_period = grace_period;
+
+        emit!(WalletSetGracePeriodEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            grace_period,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Changes the minimum timelock delay of the smart_wallet. The only way this can be
+    /// invoked is via a recursive call from execute_transaction ->
+    /// set_minimum_delay.
+    ///
+    /// The new delay also applies to [Transaction]s which are already queued:
+    /// a [Transaction] whose [Transaction::eta] is not at least `minimum_delay`
+    /// after its [Transaction::created_at] can no longer be executed and must be
+    /// proposed again.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_minimum_delay(ctx: Context<Auth>, minimum_delay: i64) -> Result<()> {
+        invariant!(minimum_delay >= 0, "delay must be positive");
+        invariant!(minimum_delay < MAX_DELAY_SECONDS, DelayTooHigh);
+
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        smart_wallet.minimum_delay = minimum_delay;
+
+        emit!(WalletSetMinimumDelayEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            minimum_delay,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
     /// Creates a new [Transaction] account, automatically signed by the creator,
     /// which must be one of the owners of the smart_wallet.
+    ///
+    /// The creator must have [PERMISSION_PROPOSE], and only signs the
+    /// [Transaction] if it has [PERMISSION_APPROVE].
     pub fn create_transaction(
         ctx: Context<CreateTransaction>,
         bump: u8,
@@ -146,34 +630,39 @@ pub mod smart_wallet {
     /// Creates a new [Transaction] account with time delay.
     #[access_control(ctx.accounts.validate())]
     pub fn create_transaction_with_timelock(
+        ctx: Context<CreateTransaction>,
+        bump: u8,
+        instructions: Vec<TXInstruction>,
+        eta: i64,
+    ) -> Result<()> {
+        create_transaction_with_bounty(ctx, bump, instructions, eta, 0, 0)
+    }
+
+    /// Creates a new [Transaction] account with time delay, which pays `bounty`
+    /// lamports from the `GokiSmartWalletDerived` wallet at `bounty_wallet_index`
+    /// to its executor.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_transaction_with_bounty(
         ctx: Context<CreateTransaction>,
         _bump: u8,
         instructions: Vec<TXInstruction>,
         eta: i64,
+        bounty: u64,
+        bounty_wallet_index: u64,
     ) -> Result<()> {
         let smart_wallet = &ctx.accounts.smart_wallet;
-        let owner_index = smart_wallet.try_owner_index(ctx.accounts.proposer.key())?;
+        let owner_index = smart_wallet
+            .try_owner_index_with_permission(ctx.accounts.proposer.key(), PERMISSION_PROPOSE)?;
 
         let clock = Clock::get()?;
         let current_ts = clock.unix_timestamp;
-        if smart_wallet.minimum_delay != 0 {
-            invariant!(
-                eta >= unwrap_int!(current_ts.checked_add(smart_wallet.minimum_delay as i64)),
-                InvalidETA
-            );
-        }
-        if eta != NO_ETA {
-            invariant!(eta >= 0, "ETA must be positive");
-            let delay = unwrap_int!(eta.checked_sub(current_ts));
-            invariant!(delay >= 0, "ETA must be in the future");
-            invariant!(delay <= MAX_DELAY_SECONDS, DelayTooHigh);
-        }
+        smart_wallet.check_eta(eta, current_ts)?;
 
         // generate the signers boolean list
         let owners = &smart_wallet.owners;
         let mut signers = Vec::new();
         signers.resize(owners.len(), false);
-        signers[owner_index] = true;
+        signers[owner_index] = smart_wallet.has_permission(owner_index, PERMISSION_APPROVE);
 
         let index = smart_wallet.num_transactions;
         let smart_wallet = &mut ctx.accounts.smart_wallet;
@@ -186,37 +675,200 @@ pub mod smart_wallet {
         tx.bump = *unwrap_int!(ctx.bumps.get("transaction"));
 
         tx.proposer = ctx.accounts.proposer.key();
+        tx.payer = ctx.accounts.payer.key();
         tx.instructions = instructions.clone();
-        tx.signers = signers;
+        tx.signers = signers.clone();
+        tx.rejections = vec![false; signers.len()];
+        tx.revoked = vec![false; signers.len()];
         tx.owner_set_seqno = smart_wallet.owner_set_seqno;
         tx.eta = eta;
 
         tx.executor = Pubkey::default();
         tx.executed_at = -1;
+        tx.created_at = current_ts;
+        tx.bounty = bounty;
+        tx.bounty_wallet_index = bounty_wallet_index;
+        tx.instructions_hash = tx.compute_instructions_hash()?;
+        tx.update_threshold_reached_at(&ctx.accounts.smart_wallet, current_ts)?;
+
+        ctx.accounts
+            .smart_wallet
+            .record_owner_activity(ctx.accounts.proposer.key(), current_ts);
 
         emit!(TransactionCreateEvent {
             smart_wallet: ctx.accounts.smart_wallet.key(),
             transaction: ctx.accounts.transaction.key(),
             proposer: ctx.accounts.proposer.key(),
+            proposer_record: None,
             instructions,
+            instructions_hash: ctx.accounts.transaction.instructions_hash,
             eta,
             timestamp: Clock::get()?.unix_timestamp
         });
         Ok(())
     }
 
+    /// Creates a new [Transaction] account whose address is derived from the
+    /// proposer and a proposer-chosen `nonce` rather than from
+    /// [SmartWallet::num_transactions], so concurrent proposals never collide.
+    ///
+    /// [Transaction::index] is still assigned from [SmartWallet::num_transactions].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_transaction_with_nonce(
+        ctx: Context<CreateTransactionWithNonce>,
+        _bump: u8,
+        _nonce: u64,
+        instructions: Vec<TXInstruction>,
+        eta: i64,
+    ) -> Result<()> {
+        instructions::create_transaction_with_nonce::handler(ctx, instructions, eta)
+    }
+
+    /// Creates a new draft [Transaction] account, whose instructions may be edited
+    /// by the proposer until [smart_wallet::finalize_transaction()] is called.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_draft_transaction(
+        ctx: Context<CreateTransaction>,
+        _bump: u8,
+        instructions: Vec<TXInstruction>,
+    ) -> Result<()> {
+        instructions::create_draft_transaction::handler(ctx, instructions)
+    }
+
+    /// Appends an instruction to a draft [Transaction], resetting its approvals.
+    #[access_control(ctx.accounts.validate())]
+    pub fn append_draft_instruction(
+        ctx: Context<EditDraftTransaction>,
+        ix: TXInstruction,
+    ) -> Result<()> {
+        instructions::append_draft_instruction::handler(ctx, ix)
+    }
+
+    /// Replaces the instruction at `index` of a draft [Transaction], resetting its approvals.
+    #[access_control(ctx.accounts.validate())]
+    pub fn replace_draft_instruction(
+        ctx: Context<EditDraftTransaction>,
+        index: u8,
+        ix: TXInstruction,
+    ) -> Result<()> {
+        instructions::replace_draft_instruction::handler(ctx, index, ix)
+    }
+
+    /// Removes the instruction at `index` of a draft [Transaction], resetting its approvals.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_draft_instruction(ctx: Context<EditDraftTransaction>, index: u8) -> Result<()> {
+        instructions::remove_draft_instruction::handler(ctx, index)
+    }
+
+    /// Finalizes a draft [Transaction], opening it for approvals.
+    ///
+    /// The [Transaction] is treated as if it were created now with the given `eta`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn finalize_transaction(ctx: Context<FinalizeTransaction>, eta: i64) -> Result<()> {
+        instructions::finalize_transaction::handler(ctx, eta)
+    }
+
+    /// Creates a new [Transaction] account on behalf of a non-owner allowed
+    /// by a [ProposerRecord]. The [Transaction] starts with zero approvals.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_proposer_transaction(
+        ctx: Context<CreateProposerTransaction>,
+        _bump: u8,
+        instructions: Vec<TXInstruction>,
+        eta: i64,
+    ) -> Result<()> {
+        instructions::create_proposer_transaction::handler(ctx, instructions, eta)
+    }
+
     /// Approves a transaction on behalf of an owner of the [SmartWallet].
     #[access_control(ctx.accounts.validate())]
     pub fn approve(ctx: Context<Approve>) -> Result<()> {
         instructions::approve::handler(ctx)
     }
 
+    /// Approves a transaction on behalf of an owner of the [SmartWallet],
+    /// failing unless `instructions_hash` matches [Transaction::instructions_hash].
+    ///
+    /// This binds the approval to the instructions, ETA, and bounty the owner verified offline.
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_with_hash(ctx: Context<Approve>, instructions_hash: [u8; 32]) -> Result<()> {
+        instructions::approve_with_hash::handler(ctx, instructions_hash)
+    }
+
+    /// Approves a transaction on behalf of every owner of the [SmartWallet] which
+    /// signed its [Transaction::approval_message] off-chain.
+    ///
+    /// The signatures are verified by Ed25519 program instructions preceding this
+    /// instruction, which are read through the instructions sysvar. This allows
+    /// a relayer to submit many approvals at once.
+    ///
+    /// Signatures of owners which already approved, or which have since
+    /// unapproved or rejected the [Transaction], are ignored; see [Transaction::revoked].
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_with_signatures(ctx: Context<ApproveWithSignatures>) -> Result<()> {
+        instructions::approve_with_signatures::handler(ctx)
+    }
+
     /// Unapproves a transaction on behalf of an owner of the [SmartWallet].
     #[access_control(ctx.accounts.validate())]
     pub fn unapprove(ctx: Context<Approve>) -> Result<()> {
         instructions::unapprove::handler(ctx)
     }
 
+    /// Rejects a transaction on behalf of an owner of the [SmartWallet].
+    ///
+    /// Once enough owners have rejected the [Transaction] that the threshold
+    /// can no longer be reached, it is marked as rejected and can never be executed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn reject(ctx: Context<Approve>) -> Result<()> {
+        instructions::reject::handler(ctx)
+    }
+
+    /// Cancels a [Transaction] on behalf of its proposer.
+    ///
+    /// The [Transaction] account is closed and its rent is refunded to
+    /// [Transaction::payer], so it can never be approved or executed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_transaction(ctx: Context<CancelTransaction>) -> Result<()> {
+        instructions::cancel_transaction::handler(ctx)
+    }
+
+    /// Vetoes a [Transaction] on behalf of the [SmartWallet::guardian],
+    /// permanently preventing it from being executed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn veto_transaction(ctx: Context<VetoTransaction>) -> Result<()> {
+        instructions::veto_transaction::handler(ctx)
+    }
+
+    /// Vetoes a [TransactionBuffer] on behalf of the [SmartWallet::guardian],
+    /// permanently preventing any of its remaining bundles from being executed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn veto_transaction_buffer(ctx: Context<VetoTransactionBuffer>) -> Result<()> {
+        instructions::veto_transaction_buffer::handler(ctx)
+    }
+
+    /// Closes a [Transaction] which has been executed or vetoed, or is past its
+    /// grace period, refunding its rent to [Transaction::payer].
+    ///
+    /// Anyone may close such a [Transaction].
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_transaction(ctx: Context<CloseTransaction>) -> Result<()> {
+        instructions::close_transaction::handler(ctx)
+    }
+
+    /// Closes a [Transaction] created before [Transaction::payer] was added,
+    /// refunding its rent to its proposer.
+    ///
+    /// Such a [Transaction] can no longer be loaded, so it can never be approved
+    /// or executed. Anyone may close it.
+    ///
+    /// The legacy layout does not record the payer of the account, so the rent
+    /// goes to the proposer even if another account paid for it.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_legacy_transaction(ctx: Context<CloseLegacyTransaction>) -> Result<()> {
+        instructions::close_legacy_transaction::handler(ctx)
+    }
+
     /// Executes the given transaction if threshold owners have signed it.
     #[access_control(ctx.accounts.validate())]
     pub fn execute_transaction(ctx: Context<ExecuteTransaction>) -> Result<()> {
@@ -226,7 +878,36 @@ pub mod smart_wallet {
             &smart_wallet.base.to_bytes(),
             &[smart_wallet.bump],
         ]];
-        do_execute_transaction(ctx, wallet_seeds)
+        do_execute_transaction(ctx, wallet_seeds, None)
+    }
+
+    /// Executes the next `num_instructions` instructions of the given transaction
+    /// if threshold owners have signed it, advancing [Transaction::executed_count].
+    ///
+    /// This allows transactions too large to execute at once to span several
+    /// Solana transactions. The [Transaction] is only marked as executed
+    /// once its last instruction has been executed.
+    ///
+    /// Once the first step has executed, the [Transaction] can no longer be
+    /// approved, unapproved, or rejected, and the remaining steps skip the owner set,
+    /// timelock, and threshold checks. The remaining steps must still execute within
+    /// the grace period, and may be aborted by cancelling or vetoing the [Transaction].
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_transaction_step(
+        ctx: Context<ExecuteTransaction>,
+        num_instructions: u8,
+    ) -> Result<()> {
+        invariant!(
+            num_instructions > 0,
+            "must execute at least one instruction"
+        );
+        let smart_wallet = &ctx.accounts.smart_wallet;
+        let wallet_seeds: &[&[&[u8]]] = &[&[
+            b"GokiSmartWallet" as &[u8],
+            &smart_wallet.base.to_bytes(),
+            &[smart_wallet.bump],
+        ]];
+        do_execute_transaction(ctx, wallet_seeds, Some(num_instructions as usize))
     }
 
     /// Executes the given transaction signed by the given derived address,
@@ -246,7 +927,69 @@ pub mod smart_wallet {
             &index.to_le_bytes(),
             &[bump],
         ]];
-        do_execute_transaction(ctx, wallet_seeds)
+        do_execute_transaction(ctx, wallet_seeds, None)
+    }
+
+    /// Initializes a [TransactionBuffer] which was allocated by the client,
+    /// automatically approved by the proposer, which must be one of the owners
+    /// of the smart_wallet.
+    #[access_control(ctx.accounts.validate())]
+    pub fn init_transaction_buffer(ctx: Context<InitTransactionBuffer>, eta: i64) -> Result<()> {
+        instructions::init_transaction_buffer::handler(ctx, eta)
+    }
+
+    /// Appends instructions to the [TransactionBundle] at `bundle_index` of a
+    /// [TransactionBuffer]. If `bundle_index` is the number of bundles in the
+    /// buffer, a new bundle is created.
+    ///
+    /// Fails if the buffer would no longer fit in its account or would have more
+    /// than [MAX_BUFFER_BUNDLES] bundles.
+    #[access_control(ctx.accounts.validate())]
+    pub fn write_transaction_buffer(
+        ctx: Context<WriteTransactionBuffer>,
+        bundle_index: u8,
+        instructions: Vec<TXInstruction>,
+    ) -> Result<()> {
+        instructions::write_transaction_buffer::handler(ctx, bundle_index, instructions)
+    }
+
+    /// Finalizes a [TransactionBuffer], preventing any further writes and
+    /// allowing owners to approve it.
+    #[access_control(ctx.accounts.validate())]
+    pub fn finalize_transaction_buffer(ctx: Context<WriteTransactionBuffer>) -> Result<()> {
+        instructions::finalize_transaction_buffer::handler(ctx)
+    }
+
+    /// Approves a [TransactionBuffer] on behalf of an owner of the [SmartWallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_transaction_buffer(ctx: Context<ApproveTransactionBuffer>) -> Result<()> {
+        instructions::approve_transaction_buffer::handler(ctx)
+    }
+
+    /// Unapproves a [TransactionBuffer] on behalf of an owner of the [SmartWallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn unapprove_transaction_buffer(ctx: Context<ApproveTransactionBuffer>) -> Result<()> {
+        instructions::unapprove_transaction_buffer::handler(ctx)
+    }
+
+    /// Executes the [TransactionBundle] at `bundle_index` of a [TransactionBuffer]
+    /// if threshold owners have approved the buffer.
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_buffer_bundle(
+        ctx: Context<ExecuteBufferBundle>,
+        bundle_index: u8,
+    ) -> Result<()> {
+        instructions::execute_buffer_bundle::handler(ctx, bundle_index)
+    }
+
+    /// Closes a [TransactionBuffer] whose bundles have all been executed, which
+    /// has been vetoed, or which is past its grace period, refunding its rent
+    /// to [TransactionBuffer::proposer].
+    ///
+    /// Anyone may close such a [TransactionBuffer].
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_transaction_buffer(ctx: Context<CloseTransactionBuffer>) -> Result<()> {
+        instructions::close_transaction_buffer::handler(ctx)
     }
 
     /// Invokes an arbitrary instruction as a PDA derived from the owner,
@@ -395,7 +1138,13 @@ pub struct CreateSmartWallet<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Accounts for [smart_wallet::set_owners] and [smart_wallet::change_threshold].
+/// Accounts for [smart_wallet::set_owners], [smart_wallet::add_owner],
+/// [smart_wallet::remove_owner], [smart_wallet::swap_owner],
+/// [smart_wallet::change_threshold], [smart_wallet::set_owner_weights],
+/// [smart_wallet::set_owner_permissions], [smart_wallet::set_execution_policy],
+/// [smart_wallet::set_guardian], [smart_wallet::set_pause_authority], [smart_wallet::unpause],
+/// [smart_wallet::set_threshold_decay], [smart_wallet::set_timelock_mode],
+/// [smart_wallet::set_grace_period], and [smart_wallet::set_minimum_delay].
 #[derive(Accounts)]
 pub struct Auth<'info> {
     /// The [SmartWallet].
@@ -420,10 +1169,10 @@ pub struct CreateTransaction<'info> {
         ],
         bump,
         payer = payer,
-        space = Transaction::space(instructions),
+        space = Transaction::space(instructions, smart_wallet.owners.len()),
     )]
     pub transaction: Account<'info, Transaction>,
-    /// One of the owners. Checked in the handler via [SmartWallet::try_owner_index].
+    /// One of the owners. Checked in the handler via [SmartWallet::try_owner_index_with_permission].
     pub proposer: Signer<'info>,
     /// Payer to create the [Transaction].
     #[account(mut)]
@@ -436,11 +1185,12 @@ pub struct CreateTransaction<'info> {
 #[derive(Accounts)]
 pub struct ExecuteTransaction<'info> {
     /// The [SmartWallet].
+    #[account(mut)]
     pub smart_wallet: Account<'info, SmartWallet>,
     /// The [Transaction] to execute.
     #[account(mut)]
     pub transaction: Account<'info, Transaction>,
-    /// An owner of the [SmartWallet].
+    /// The executor, which must be allowed by [SmartWallet::execution_policy].
     pub owner: Signer<'info>,
 }
 
@@ -476,25 +1226,118 @@ pub struct CreateSubaccountInfo<'info> {
     pub system_program: Program<'info, System>,
 }
 
-fn do_execute_transaction(ctx: Context<ExecuteTransaction>, seeds: &[&[&[u8]]]) -> Result<()> {
-    for ix in ctx.accounts.transaction.instructions.iter() {
+/// Executes the instructions of the [Transaction] following [Transaction::executed_count],
+/// up to `max_instructions` of them if executing a single step.
+fn do_execute_transaction(
+    ctx: Context<ExecuteTransaction>,
+    seeds: &[&[&[u8]]],
+    max_instructions: Option<usize>,
+) -> Result<()> {
+    let num_instructions = ctx.accounts.transaction.instructions.len();
+    let start = ctx.accounts.transaction.executed_count as usize;
+    let end = match max_instructions {
+        Some(max_instructions) => {
+            unwrap_int!(start.checked_add(max_instructions)).min(num_instructions)
+        }
+        None => num_instructions,
+    };
+    for ix in ctx.accounts.transaction.instructions[start..end].iter() {
         solana_program::program::invoke_signed(&(ix).into(), ctx.remaining_accounts, seeds)?;
     }
+    ctx.accounts.transaction.executed_count = end as u64;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    if max_instructions.is_some() {
+        emit!(TransactionExecuteStepEvent {
+            smart_wallet: ctx.accounts.smart_wallet.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.owner.key(),
+            start_index: start as u64,
+            executed_count: end as u64,
+            timestamp: current_ts
+        });
+    }
+    if end < num_instructions {
+        // The executed instructions may have modified the smart wallet.
+        let smart_wallet = &mut ctx.accounts.smart_wallet;
+        smart_wallet.reload()?;
+        // the executed instructions were approved by the owners.
+        smart_wallet.last_activity_ts = current_ts;
+        return Ok(());
+    }
+
+    // Pay the bounty to the executor.
+    let bounty = ctx.accounts.transaction.bounty;
+    if bounty > 0 {
+        pay_bounty(
+            ctx.accounts.smart_wallet.key(),
+            ctx.accounts.transaction.bounty_wallet_index,
+            ctx.accounts.owner.key(),
+            bounty,
+            ctx.remaining_accounts,
+        )?;
+    }
 
     // Burn the transaction to ensure one time use.
     let tx = &mut ctx.accounts.transaction;
     tx.executor = ctx.accounts.owner.key();
-    tx.executed_at = Clock::get()?.unix_timestamp;
+    tx.executed_at = current_ts;
+
+    // The executed instructions may have modified the smart wallet.
+    let smart_wallet = &mut ctx.accounts.smart_wallet;
+    smart_wallet.reload()?;
+    // the executed instructions were approved by the owners.
+    smart_wallet.last_activity_ts = current_ts;
 
     emit!(TransactionExecuteEvent {
         smart_wallet: ctx.accounts.smart_wallet.key(),
         transaction: ctx.accounts.transaction.key(),
         executor: ctx.accounts.owner.key(),
+        bounty,
         timestamp: Clock::get()?.unix_timestamp
     });
     Ok(())
 }
 
+/// Transfers `bounty` lamports from the bounty wallet of the [Transaction] to the executor.
+///
+/// The bounty wallet, the writable executor, and the [System] program must be
+/// passed as remaining accounts.
+fn pay_bounty(
+    smart_wallet: Pubkey,
+    bounty_wallet_index: u64,
+    executor: Pubkey,
+    bounty: u64,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let (bounty_wallet, bump) = Pubkey::find_program_address(
+        &[
+            b"GokiSmartWalletDerived" as &[u8],
+            &smart_wallet.to_bytes(),
+            &bounty_wallet_index.to_le_bytes(),
+        ],
+        &crate::ID,
+    );
+    invariant!(
+        remaining_accounts
+            .iter()
+            .any(|account| account.key() == bounty_wallet),
+        BountyWalletMissing
+    );
+
+    solana_program::program::invoke_signed(
+        &solana_program::system_instruction::transfer(&bounty_wallet, &executor, bounty),
+        remaining_accounts,
+        &[&[
+            b"GokiSmartWalletDerived" as &[u8],
+            &smart_wallet.to_bytes(),
+            &bounty_wallet_index.to_le_bytes(),
+            &[bump],
+        ]],
+    )?;
+    Ok(())
+}
+
 /// Program errors.
 #[error_code]
 pub enum ErrorCode {
@@ -512,7 +1355,7 @@ pub enum ErrorCode {
     TransactionNotReady,
     #[msg("The given transaction has already been executed.")]
     AlreadyExecuted,
-    #[msg("Threshold must be less than or equal to the number of owners.")]
+    #[msg("Threshold must be less than or equal to the total weight of the owners.")]
     InvalidThreshold,
     #[msg("Owner set has changed since the creation of the transaction.")]
     OwnerSetChanged,
@@ -528,4 +1371,66 @@ pub enum ErrorCode {
     BufferBundleNotFinalized,
     #[msg("Buffer bundle has already been executed.")]
     BufferBundleExecuted,
+    #[msg("Transaction must be executed, vetoed, or past its grace period to be closed.")]
+    TransactionNotClosable,
+    #[msg("The account is not a transaction using the legacy layout.")]
+    NotLegacyTransaction,
+    #[msg("The transaction buffer is full.")]
+    BufferFull,
+    #[msg("The given owner is already part of this smart wallet.")]
+    DuplicateOwner,
+    #[msg("The smart wallet cannot hold any more owners. Resize it first.")]
+    TooManyOwners,
+    #[msg("Threshold must be at least 1.")]
+    ZeroThreshold,
+    #[msg("The smart wallet must have at least one owner.")]
+    EmptyOwners,
+    #[msg("Owner weights must be empty or contain one non-zero weight per owner.")]
+    InvalidOwnerWeights,
+    #[msg("The smart wallet must be migrated to the current account layout.")]
+    WalletNotMigrated,
+    #[msg("The smart wallet already uses the current account layout.")]
+    WalletAlreadyMigrated,
+    #[msg("Owner permissions must be empty or contain one valid bitmask per owner.")]
+    InvalidOwnerPermissions,
+    #[msg("The given owner does not have permission to perform this action.")]
+    OwnerMissingPermission,
+    #[msg("Keepers must be unique, within the limit, and set under the Keepers execution policy.")]
+    InvalidKeepers,
+    #[msg("The bounty wallet of the transaction was not provided.")]
+    BountyWalletMissing,
+    #[msg("The transaction has been vetoed by the guardian.")]
+    TransactionVetoed,
+    #[msg("The smart wallet has no guardian.")]
+    NoGuardian,
+    #[msg("The smart wallet is paused.")]
+    WalletPaused,
+    #[msg("Pausing requires the pause authority or enough owner signatures.")]
+    PauseNotAuthorized,
+    #[msg("Recovery keys must be unique and the threshold and delay must be positive.")]
+    InvalidRecoveryConfig,
+    #[msg("Not enough recovery keys signed to initiate a recovery.")]
+    RecoveryNotAuthorized,
+    #[msg("A recovery is already pending.")]
+    RecoveryAlreadyPending,
+    #[msg("No recovery is pending.")]
+    RecoveryNotPending,
+    #[msg("The recovery delay has not passed.")]
+    RecoveryNotReady,
+    #[msg("The smart wallet has not been inactive for long enough.")]
+    WalletNotInactive,
+    #[msg("The transaction has been rejected by the owners.")]
+    TransactionRejected,
+    #[msg("Invalid Ed25519 signature verification instruction.")]
+    InvalidSignatureInstruction,
+    #[msg("No signatures of the approval message by owners yet to approve were found.")]
+    NoApprovalSignatures,
+    #[msg("The instructions hash does not match the transaction.")]
+    InstructionsHashMismatch,
+    #[msg("The transaction is a draft and must be finalized first.")]
+    TransactionIsDraft,
+    #[msg("The transaction is not a draft.")]
+    TransactionNotDraft,
+    #[msg("Execution of the transaction has already started.")]
+    ExecutionStarted,
 }
@@ -14,9 +14,13 @@ pub struct SmartWallet {
     /// Bump seed for deriving PDA seeds.
     pub bump: u8,
 
-    /// Minimum number of owner approvals needed to sign a [Transaction].
+    /// Minimum total weight of owner approvals needed to sign a [Transaction].
+    ///
+    /// If [SmartWallet::owner_weights] is empty, this is the minimum number of owner approvals.
     pub threshold: u64,
     /// Minimum delay between approval and execution, in seconds.
+    ///
+    /// See [SmartWallet::timelock_mode] for when the delay starts counting.
     pub minimum_delay: i64,
     /// Time after the ETA until a [Transaction] expires.
     pub grace_period: i64,
@@ -34,8 +38,74 @@ pub struct SmartWallet {
     /// Owners of the [SmartWallet].
     pub owners: Vec<Pubkey>,
 
+    /// Version of the layout of the [SmartWallet] account.
+    ///
+    /// [SmartWallet]s created before [SmartWallet::owner_weights] was added have
+    /// a version of 0 and must be migrated via [crate::smart_wallet::migrate_smart_wallet].
+    pub layout_version: u64,
+
+    /// Total number of [Transaction]s which have been closed or cancelled.
+    ///
+    /// A [Transaction] derived from an index below [SmartWallet::num_transactions]
+    /// whose account no longer exists has been closed. This does not apply to
+    /// [Transaction]s created via [crate::smart_wallet::create_transaction_with_nonce],
+    /// which are derived from their nonce instead.
+    pub num_closed_transactions: u64,
+
+    /// Account which may veto any [Transaction] which has not been executed.
+    ///
+    /// If [Pubkey::default], the [SmartWallet] has no guardian.
+    pub guardian: Pubkey,
+
+    /// Account which may pause the [SmartWallet].
+    ///
+    /// If [Pubkey::default], the [SmartWallet] has no pause authority.
+    pub pause_authority: Pubkey,
+    /// Number of owners which may pause the [SmartWallet] by signing together.
+    ///
+    /// If 0, owners may not pause the [SmartWallet] without the pause authority.
+    /// If greater than the number of owners, every owner must sign.
+    pub pause_threshold: u64,
+    /// When the [SmartWallet] was paused. 0 if not paused.
+    ///
+    /// While paused, only [Transaction]s which unpause the [SmartWallet] may be executed.
+    pub paused_at: i64,
+
+    /// When an owner last created or approved a [Transaction] or [TransactionBuffer],
+    /// when one was last executed, or when the [Beneficiary] was last configured
+    /// or the [SmartWallet] was last recovered.
+    pub last_activity_ts: i64,
+
+    /// Period after which the threshold required to execute a [Transaction]
+    /// decreases by 1, counted from the creation of the [Transaction], in seconds.
+    ///
+    /// If 0, the threshold does not decay.
+    pub threshold_decay_period: i64,
+    /// Minimum threshold the threshold may decay to.
+    pub threshold_floor: u64,
+
     /// Extra space for program upgrades.
-    pub reserved: [u64; 16],
+    pub reserved: [u64; 1],
+
+    /// `owner_weights[index]` is the voting weight of `owners[index]`.
+    ///
+    /// If empty, every owner has a weight of 1.
+    pub owner_weights: Vec<u64>,
+    /// `owner_permissions[index]` is the permission bitmask of `owners[index]`,
+    /// composed of [crate::PERMISSION_PROPOSE], [crate::PERMISSION_APPROVE],
+    /// and [crate::PERMISSION_EXECUTE].
+    ///
+    /// If empty, every owner has [crate::PERMISSION_ALL].
+    pub owner_permissions: Vec<u8>,
+
+    /// Who may execute approved [Transaction]s.
+    pub execution_policy: ExecutionPolicy,
+    /// Non-owners which may execute approved [Transaction]s
+    /// under [ExecutionPolicy::Keepers], up to [crate::MAX_KEEPERS].
+    pub keepers: Vec<Pubkey>,
+
+    /// When the [SmartWallet::minimum_delay] starts counting.
+    pub timelock_mode: TimelockMode,
 }
 
 impl SmartWallet {
@@ -44,7 +114,72 @@ impl SmartWallet {
         4 // Anchor discriminator
             + std::mem::size_of::<SmartWallet>()
             + 4 // 4 = the Vec discriminator
-            + std::mem::size_of::<Pubkey>() * (max_owners as usize)
+            + SmartWallet::SPACE_PER_OWNER * (max_owners as usize)
+            + std::mem::size_of::<Pubkey>() * crate::MAX_KEEPERS
+    }
+
+    /// Space used by each owner: its key, its weight, and its permissions.
+    const SPACE_PER_OWNER: usize =
+        std::mem::size_of::<Pubkey>() + std::mem::size_of::<u64>() + std::mem::size_of::<u8>();
+
+    /// Computes the maximum number of owners a [SmartWallet] account
+    /// of `data_len` bytes can hold.
+    ///
+    /// This is usually 0 for [SmartWallet]s created before [SmartWallet::owner_weights]
+    /// was added until they are resized via [crate::smart_wallet::resize_smart_wallet].
+    pub fn max_owners(data_len: usize) -> usize {
+        data_len.saturating_sub(SmartWallet::space(0)) / SmartWallet::SPACE_PER_OWNER
+    }
+
+    /// Returns true if the [SmartWallet] uses the current account layout.
+    pub fn is_migrated(&self) -> bool {
+        self.layout_version == crate::SMART_WALLET_LAYOUT_VERSION
+    }
+
+    /// Gets the voting weight of the owner at `index`.
+    pub fn owner_weight(&self, index: usize) -> u64 {
+        self.owner_weights.get(index).copied().unwrap_or(1)
+    }
+
+    /// Gets the permission bitmask of the owner at `index`.
+    pub fn owner_permission(&self, index: usize) -> u8 {
+        self.owner_permissions
+            .get(index)
+            .copied()
+            .unwrap_or(crate::PERMISSION_ALL)
+    }
+
+    /// Returns true if the owner at `index` has all of the given permissions.
+    pub fn has_permission(&self, index: usize, permission: u8) -> bool {
+        self.owner_permission(index) & permission == permission
+    }
+
+    /// Gets the index of the key in the owners Vec if it has the given permission, or error
+    pub fn try_owner_index_with_permission(&self, key: Pubkey, permission: u8) -> Result<usize> {
+        let owner_index = self.try_owner_index(key)?;
+        invariant!(
+            self.has_permission(owner_index, permission),
+            OwnerMissingPermission
+        );
+        Ok(owner_index)
+    }
+
+    /// Computes the total weight of the owners which approved,
+    /// where `signers[index]` is true iff `owners[index]` approved.
+    ///
+    /// Approvals of owners which no longer have [crate::PERMISSION_APPROVE] are ignored.
+    pub fn approval_weight(&self, signers: &[bool]) -> Result<u64> {
+        let mut weight: u64 = 0;
+        for (index, _) in signers
+            .iter()
+            .enumerate()
+            .filter(|(_, did_sign)| **did_sign)
+        {
+            if self.has_permission(index, crate::PERMISSION_APPROVE) {
+                weight = unwrap_int!(weight.checked_add(self.owner_weight(index)));
+            }
+        }
+        Ok(weight)
     }
 
     /// Gets the index of the key in the owners Vec, or None
@@ -56,6 +191,107 @@ impl SmartWallet {
     pub fn try_owner_index(&self, key: Pubkey) -> Result<usize> {
         Ok(unwrap_opt!(self.owner_index_opt(key), InvalidOwner))
     }
+
+    /// Records that `key` acted on the [SmartWallet] at `timestamp`, if `key` is an owner.
+    pub fn record_owner_activity(&mut self, key: Pubkey, timestamp: i64) {
+        if self.owner_index_opt(key).is_some() {
+            self.last_activity_ts = timestamp;
+        }
+    }
+
+    /// Computes the threshold required to execute a [Transaction] created at
+    /// `created_at`, taking the threshold decay into account.
+    pub fn effective_threshold(&self, created_at: i64, current_ts: i64) -> Result<u64> {
+        if self.threshold_decay_period == 0 {
+            return Ok(self.threshold);
+        }
+        let elapsed = unwrap_int!(current_ts.checked_sub(created_at)).max(0);
+        let steps = unwrap_int!(elapsed.checked_div(self.threshold_decay_period)) as u64;
+        Ok(self
+            .threshold
+            .saturating_sub(steps)
+            .max(self.minimum_threshold()))
+    }
+
+    /// The lowest threshold a [Transaction] may ever require, i.e. the
+    /// [SmartWallet::threshold_floor] if the threshold decays.
+    pub fn minimum_threshold(&self) -> u64 {
+        if self.threshold_decay_period == 0 {
+            self.threshold
+        } else {
+            self.threshold_floor.min(self.threshold)
+        }
+    }
+
+    /// Computes when `signers` reached the [SmartWallet::effective_threshold] of a
+    /// [Transaction] or [TransactionBuffer] created at `created_at`, given the
+    /// `recorded_at` time last recorded on approval. Returns [None] if they have
+    /// not reached it as of `current_ts`.
+    ///
+    /// If no time was recorded, the threshold can only have been reached by
+    /// decaying down to the approval weight. Otherwise, e.g. if the threshold was
+    /// lowered via [crate::smart_wallet::change_threshold], this returns [None]
+    /// until an owner approves again to record the time.
+    pub fn threshold_reached_at(
+        &self,
+        signers: &[bool],
+        created_at: i64,
+        recorded_at: i64,
+        current_ts: i64,
+    ) -> Result<Option<i64>> {
+        let approval_weight = self.approval_weight(signers)?;
+        if approval_weight < self.effective_threshold(created_at, current_ts)? {
+            return Ok(None);
+        }
+        if recorded_at != 0 {
+            return Ok(Some(recorded_at));
+        }
+        if self.threshold_decay_period == 0 || approval_weight >= self.threshold {
+            return Ok(None);
+        }
+        let steps = unwrap_int!(i64::try_from(self.threshold.saturating_sub(approval_weight)).ok());
+        let decay_time = unwrap_int!(steps.checked_mul(self.threshold_decay_period));
+        Ok(Some(unwrap_int!(created_at.checked_add(decay_time))))
+    }
+
+    /// Returns true if the [SmartWallet] is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused_at != 0
+    }
+
+    /// Checks that `executor` may execute approved [Transaction]s
+    /// under the [SmartWallet::execution_policy].
+    pub fn check_executor(&self, executor: Pubkey) -> Result<()> {
+        match self.execution_policy {
+            ExecutionPolicy::Owners => {
+                self.try_owner_index_with_permission(executor, crate::PERMISSION_EXECUTE)?;
+            }
+            ExecutionPolicy::Keepers => {
+                if !self.keepers.contains(&executor) {
+                    self.try_owner_index_with_permission(executor, crate::PERMISSION_EXECUTE)?;
+                }
+            }
+            ExecutionPolicy::Anyone => {}
+        }
+        Ok(())
+    }
+
+    /// Checks that a newly proposed ETA satisfies the timelock of the [SmartWallet].
+    pub fn check_eta(&self, eta: i64, current_ts: i64) -> Result<()> {
+        if self.minimum_delay != 0 && self.timelock_mode == TimelockMode::Creation {
+            invariant!(
+                eta >= unwrap_int!(current_ts.checked_add(self.minimum_delay)),
+                InvalidETA
+            );
+        }
+        if eta != crate::NO_ETA {
+            invariant!(eta >= 0, "ETA must be positive");
+            let delay = unwrap_int!(eta.checked_sub(current_ts));
+            invariant!(delay >= 0, "ETA must be in the future");
+            invariant!(delay <= crate::MAX_DELAY_SECONDS, DelayTooHigh);
+        }
+        Ok(())
+    }
 }
 
 /// A [Transaction] is a series of instructions that may be executed
@@ -90,21 +326,340 @@ pub struct Transaction {
     pub executor: Pubkey,
     /// When the transaction was executed. -1 if not executed.
     pub executed_at: i64,
+    /// When the transaction was created.
+    pub created_at: i64,
+    /// The account that paid for the [Transaction] account.
+    /// Receives the rent when the [Transaction] is closed.
+    pub payer: Pubkey,
+    /// Lamports paid to the executor when the [Transaction] is executed.
+    pub bounty: u64,
+    /// Index of the `GokiSmartWalletDerived` wallet which pays the [Transaction::bounty].
+    pub bounty_wallet_index: u64,
+    /// The guardian that vetoed the [Transaction], or [Pubkey::default] if not vetoed.
+    pub vetoer: Pubkey,
+    /// When the transaction was vetoed. 0 if not vetoed.
+    pub vetoed_at: i64,
+    /// `rejections[index]` is true iff `[SmartWallet]::owners[index]` rejected the transaction.
+    pub rejections: Vec<bool>,
+    /// When enough owners rejected the transaction that it can no longer be approved.
+    /// 0 if not rejected.
+    pub rejected_at: i64,
+    /// When the approvals of the [Transaction] reached the threshold.
+    /// 0 if the [Transaction] does not have enough approvals, or only reached the
+    /// threshold as it decayed; see [SmartWallet::threshold_reached_at].
+    ///
+    /// Under [TimelockMode::ThresholdReached], the [SmartWallet::minimum_delay]
+    /// is counted from this timestamp.
+    pub threshold_reached_at: i64,
+    /// `revoked[index]` is true iff `[SmartWallet]::owners[index]` unapproved or
+    /// rejected the transaction since they last approved it on-chain.
+    ///
+    /// Off-chain approvals of revoked owners are ignored by
+    /// [crate::smart_wallet::approve_with_signatures], so their signatures cannot be replayed.
+    pub revoked: Vec<bool>,
+    /// Hash of everything executing the [Transaction] depends on, computed via
+    /// [Transaction::compute_instructions_hash] when the [Transaction] is created,
+    /// edited, or finalized.
+    pub instructions_hash: [u8; 32],
+    /// True while the [Transaction] is a draft whose instructions may still be
+    /// edited by its proposer. Drafts may not be approved or executed.
+    pub draft: bool,
+    /// Number of [Transaction::instructions] which have been executed.
+    ///
+    /// [crate::smart_wallet::execute_transaction_step] executes the instructions
+    /// following this cursor. [Transaction::executed_at] is only set once
+    /// every instruction has been executed.
+    pub executed_count: u64,
 }
 
 impl Transaction {
-    /// Computes the space a [Transaction] uses.
-    pub fn space(instructions: Vec<TXInstruction>) -> usize {
+    /// Computes the space a [Transaction] uses for a [SmartWallet] with `num_owners` owners.
+    pub fn space(instructions: Vec<TXInstruction>, num_owners: usize) -> usize {
         4  // Anchor discriminator
             + std::mem::size_of::<Transaction>()
             + 4 // Vec discriminator
             + (instructions.iter().map(|ix| ix.space()).sum::<usize>())
+            + (4 + num_owners) // signers
+            + (4 + num_owners) // rejections
+            + (4 + num_owners) // revoked
     }
 
     /// Number of signers.
     pub fn num_signers(&self) -> usize {
         self.signers.iter().filter(|&did_sign| *did_sign).count()
     }
+
+    /// Canonical hash of the [Transaction]: the SHA-256 hash of the Borsh serialization
+    /// of its [Transaction::instructions], followed by its [Transaction::eta],
+    /// [Transaction::bounty], and [Transaction::bounty_wallet_index].
+    pub fn compute_instructions_hash(&self) -> Result<[u8; 32]> {
+        Ok(solana_program::hash::hashv(&[
+            &self.instructions.try_to_vec()?,
+            &self.eta.to_le_bytes(),
+            &self.bounty.to_le_bytes(),
+            &self.bounty_wallet_index.to_le_bytes(),
+        ])
+        .to_bytes())
+    }
+
+    /// The message an owner signs to approve the [Transaction] at `key` off-chain:
+    /// a domain-separated digest of the [SmartWallet], the [Transaction] and its
+    /// [Transaction::index], the owner set sequence number, and the
+    /// [Transaction::instructions_hash].
+    ///
+    /// The index differs between [Transaction]s re-created at the same nonce address,
+    /// so signatures for a closed [Transaction] cannot approve its successor.
+    pub fn approval_message(&self, key: Pubkey) -> Result<[u8; 32]> {
+        Ok(solana_program::hash::hashv(&[
+            crate::APPROVAL_MESSAGE_DOMAIN,
+            self.smart_wallet.as_ref(),
+            key.as_ref(),
+            &self.index.to_le_bytes(),
+            &self.owner_set_seqno.to_le_bytes(),
+            &self.instructions_hash,
+        ])
+        .to_bytes())
+    }
+
+    /// Returns true if the [Transaction] has been vetoed by a guardian.
+    pub fn is_vetoed(&self) -> bool {
+        self.vetoer != Pubkey::default()
+    }
+
+    /// Records when the approvals of the [Transaction] first reached the threshold
+    /// of the [SmartWallet], clearing it if the approvals drop below the threshold.
+    pub fn update_threshold_reached_at(
+        &mut self,
+        smart_wallet: &SmartWallet,
+        current_ts: i64,
+    ) -> Result<()> {
+        let approval_weight = smart_wallet.approval_weight(&self.signers)?;
+        let threshold = smart_wallet.effective_threshold(self.created_at, current_ts)?;
+        if approval_weight < threshold {
+            self.threshold_reached_at = 0;
+        } else if self.threshold_reached_at == 0 {
+            self.threshold_reached_at = current_ts;
+        }
+        Ok(())
+    }
+
+    /// Returns true if the [Transaction] has been rejected by the owners.
+    pub fn is_rejected(&self) -> bool {
+        self.rejected_at != 0
+    }
+}
+
+/// The layout of [SmartWallet] accounts created before [SmartWallet::owner_weights]
+/// and the fields following it were added.
+///
+/// Shrinking the owners of such a [SmartWallet] left stale owner keys after its
+/// reserved space, which would be read as the fields following it.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+pub struct LegacySmartWallet {
+    /// Base used to derive.
+    pub base: Pubkey,
+    /// Bump seed for deriving PDA seeds.
+    pub bump: u8,
+    /// Minimum number of owner approvals needed to sign a [Transaction].
+    pub threshold: u64,
+    /// Minimum delay between approval and execution, in seconds.
+    pub minimum_delay: i64,
+    /// Time after the ETA until a [Transaction] expires.
+    pub grace_period: i64,
+    /// Sequence of the ownership set.
+    pub owner_set_seqno: u32,
+    /// Total number of [Transaction]s on this [SmartWallet].
+    pub num_transactions: u64,
+    /// Owners of the [SmartWallet].
+    pub owners: Vec<Pubkey>,
+    /// Reserved space, which now holds [SmartWallet::layout_version]
+    /// followed by the fields preceding [SmartWallet::owner_weights].
+    pub reserved: [u64; 16],
+}
+
+impl LegacySmartWallet {
+    /// Deserializes the account data of a [SmartWallet] using the legacy layout,
+    /// returning it along with the length of the data it was read from.
+    ///
+    /// Fails if the account is not a [SmartWallet] or has already been migrated.
+    pub fn try_from_account_data(data: &[u8]) -> Result<(LegacySmartWallet, usize)> {
+        invariant!(
+            data.len() >= 8
+                && data[..8] == <SmartWallet as anchor_lang::Discriminator>::discriminator(),
+            "smart_wallet discriminator"
+        );
+        let mut rest = &data[8..];
+        let legacy = LegacySmartWallet::deserialize(&mut rest)?;
+        invariant!(legacy.reserved[0] == 0, WalletAlreadyMigrated);
+        Ok((legacy, data.len() - rest.len()))
+    }
+}
+
+/// The layout of [Transaction] accounts created before [Transaction::payer]
+/// and the fields following it were added.
+///
+/// These accounts can no longer be loaded as a [Transaction], so they may never
+/// be approved or executed, and may only be closed via
+/// [crate::smart_wallet::close_legacy_transaction].
+///
+/// The legacy layout does not record who paid for the account, so its rent is
+/// refunded to [LegacyTransaction::proposer], which may differ from the payer.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq)]
+pub struct LegacyTransaction {
+    /// The [SmartWallet] account this transaction belongs to.
+    pub smart_wallet: Pubkey,
+    /// The auto-incremented integer index of the transaction.
+    pub index: u64,
+    /// Bump seed.
+    pub bump: u8,
+
+    /// The proposer of the [Transaction].
+    pub proposer: Pubkey,
+    /// The instruction.
+    pub instructions: Vec<TXInstruction>,
+    /// `signers[index]` is true iff `[SmartWallet]::owners[index]` signed the transaction.
+    pub signers: Vec<bool>,
+    /// Owner set sequence number.
+    pub owner_set_seqno: u32,
+    /// Estimated time the [Transaction] will be executed.
+    pub eta: i64,
+
+    /// The account that executed the [Transaction].
+    pub executor: Pubkey,
+    /// When the transaction was executed. -1 if not executed.
+    pub executed_at: i64,
+}
+
+impl LegacyTransaction {
+    /// Deserializes the account data of a [Transaction] using the legacy layout.
+    ///
+    /// Fails if the account is not a [Transaction] or can be loaded using the current layout.
+    /// Every current [Transaction] has a [Transaction::payer], so legacy accounts whose
+    /// trailing padding happens to parse as a [Transaction] are still detected.
+    pub fn try_from_account_data(data: &[u8]) -> Result<LegacyTransaction> {
+        invariant!(
+            data.len() >= 8
+                && data[..8] == <Transaction as anchor_lang::Discriminator>::discriminator(),
+            NotLegacyTransaction
+        );
+        if let Ok(tx) = Transaction::try_deserialize(&mut &data[..]) {
+            invariant!(tx.payer == Pubkey::default(), NotLegacyTransaction);
+        }
+        Ok(LegacyTransaction::deserialize(&mut &data[8..])?)
+    }
+}
+
+/// A [TransactionBuffer] holds a set of instructions too large to fit in a single
+/// [Transaction]. It is written to over several instructions, finalized, approved
+/// by the owners of the [SmartWallet], then executed one [TransactionBundle] at a time.
+///
+/// The account must be allocated by the client before calling
+/// [crate::smart_wallet::init_transaction_buffer].
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct TransactionBuffer {
+    /// The [SmartWallet] account this buffer belongs to.
+    pub smart_wallet: Pubkey,
+    /// The proposer of the [TransactionBuffer]. Only the proposer may write to the buffer.
+    pub proposer: Pubkey,
+    /// Owner set sequence number.
+    pub owner_set_seqno: u32,
+    /// Estimated time the bundles of the [TransactionBuffer] may be executed.
+    ///
+    /// See [Transaction::eta].
+    pub eta: i64,
+    /// When the buffer was created.
+    pub created_at: i64,
+    /// When the buffer was finalized. -1 if not finalized.
+    pub finalized_at: i64,
+    /// The [SmartWallet::guardian] that vetoed the buffer, if any.
+    pub vetoer: Pubkey,
+    /// When the buffer was vetoed. 0 if not vetoed.
+    pub vetoed_at: i64,
+    /// When the approvals of the buffer reached the threshold of the [SmartWallet].
+    /// 0 if the buffer does not have enough approvals.
+    ///
+    /// See [Transaction::threshold_reached_at].
+    pub threshold_reached_at: i64,
+    /// `signers[index]` is true iff `[SmartWallet]::owners[index]` approved the buffer.
+    pub signers: Vec<bool>,
+    /// The bundles of instructions, executed by index.
+    pub bundles: Vec<TransactionBundle>,
+}
+
+impl TransactionBuffer {
+    /// Computes the space a [TransactionBuffer] uses.
+    pub fn space(max_owners: u8, bundles: &[TransactionBundle]) -> usize {
+        8 // Anchor discriminator
+            + std::mem::size_of::<TransactionBuffer>()
+            + (max_owners as usize)
+            + (bundles.iter().map(|b| b.space()).sum::<usize>())
+    }
+
+    /// Returns true if the buffer has been finalized.
+    pub fn is_finalized(&self) -> bool {
+        self.finalized_at != -1
+    }
+
+    /// Returns true if the buffer has been vetoed by a guardian.
+    pub fn is_vetoed(&self) -> bool {
+        self.vetoer != Pubkey::default()
+    }
+
+    /// Returns true if any [TransactionBundle] of the buffer has been executed.
+    pub fn is_executing(&self) -> bool {
+        self.bundles.iter().any(|b| b.is_executed())
+    }
+
+    /// Returns true if every [TransactionBundle] of the buffer has been executed.
+    pub fn is_executed(&self) -> bool {
+        self.is_finalized() && self.bundles.iter().all(|b| b.is_executed())
+    }
+
+    /// Records when the approvals of the buffer first reached the threshold
+    /// of the [SmartWallet], clearing it if the approvals drop below the threshold.
+    pub fn update_threshold_reached_at(
+        &mut self,
+        smart_wallet: &SmartWallet,
+        current_ts: i64,
+    ) -> Result<()> {
+        let approval_weight = smart_wallet.approval_weight(&self.signers)?;
+        let threshold = smart_wallet.effective_threshold(self.created_at, current_ts)?;
+        if approval_weight < threshold {
+            self.threshold_reached_at = 0;
+        } else if self.threshold_reached_at == 0 {
+            self.threshold_reached_at = current_ts;
+        }
+        Ok(())
+    }
+}
+
+/// A set of instructions in a [TransactionBuffer] which are executed together.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct TransactionBundle {
+    /// The account that executed the bundle.
+    pub executor: Pubkey,
+    /// When the bundle was executed. -1 if not executed.
+    pub executed_at: i64,
+    /// The instructions of the bundle.
+    pub instructions: Vec<TXInstruction>,
+}
+
+impl TransactionBundle {
+    /// Space that a [TransactionBundle] takes up.
+    pub fn space(&self) -> usize {
+        std::mem::size_of::<TransactionBundle>()
+            + (self
+                .instructions
+                .iter()
+                .map(|ix| 4 + 4 + ix.space()) // 4 + 4 = the keys and data Vec discriminators
+                .sum::<usize>())
+    }
+
+    /// Returns true if the bundle has been executed.
+    pub fn is_executed(&self) -> bool {
+        self.executed_at != -1
+    }
 }
 
 /// Instruction.
@@ -125,6 +680,12 @@ impl TXInstruction {
             + (self.keys.len() as usize) * std::mem::size_of::<TXAccountMeta>()
             + (self.data.len() as usize)
     }
+
+    /// Returns true if this instruction invokes [crate::smart_wallet::unpause].
+    pub fn is_unpause(&self) -> bool {
+        self.program_id == crate::ID
+            && self.data == anchor_lang::InstructionData::data(&crate::instruction::Unpause {})
+    }
 }
 
 /// Account metadata used to define [TXInstruction]s
@@ -164,6 +725,35 @@ impl From<TXAccountMeta> for solana_program::instruction::AccountMeta {
     }
 }
 
+/// Who may execute approved [Transaction]s of a [SmartWallet].
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord,
+)]
+#[repr(u8)]
+pub enum ExecutionPolicy {
+    /// Only owners with [crate::PERMISSION_EXECUTE] may execute.
+    #[default]
+    Owners = 0,
+    /// Owners with [crate::PERMISSION_EXECUTE] and the [SmartWallet::keepers] may execute.
+    Keepers = 1,
+    /// Anyone may execute.
+    Anyone = 2,
+}
+
+/// When the [SmartWallet::minimum_delay] of a [SmartWallet] starts counting.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord,
+)]
+#[repr(u8)]
+pub enum TimelockMode {
+    /// The delay is counted from the creation of the [Transaction], via its ETA.
+    #[default]
+    Creation = 0,
+    /// The delay is counted from when the approvals of the [Transaction]
+    /// reached the threshold.
+    ThresholdReached = 1,
+}
+
 /// Type of Subaccount.
 #[derive(
     AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord,
@@ -198,3 +788,94 @@ impl SubaccountInfo {
     /// Number of bytes that a [SubaccountInfo] uses.
     pub const LEN: usize = 32 + 1 + 8;
 }
+
+/// Allows a non-owner to propose [Transaction]s on behalf of a [SmartWallet].
+///
+/// [Transaction]s created by an allowed proposer start with zero approvals.
+#[account]
+#[derive(Copy, Default, Debug, PartialEq, Eq)]
+pub struct ProposerRecord {
+    /// The [SmartWallet].
+    pub smart_wallet: Pubkey,
+    /// The allowed proposer.
+    pub proposer: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// The Unix timestamp when the proposer was added.
+    pub added_at: i64,
+}
+
+impl ProposerRecord {
+    /// Number of bytes that a [ProposerRecord] uses.
+    pub const LEN: usize = 32 + 32 + 1 + 8;
+}
+
+/// Social recovery configuration of a [SmartWallet].
+///
+/// A threshold of the recovery keys may propose a new owner set, which
+/// replaces the owners of the [SmartWallet] after the recovery delay unless
+/// cancelled by a current owner.
+#[account]
+#[derive(Default, Debug, PartialEq)]
+pub struct Recovery {
+    /// The [SmartWallet].
+    pub smart_wallet: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// Number of recovery keys which must sign to initiate a recovery.
+    pub threshold: u64,
+    /// Time between the initiation and the completion of a recovery, in seconds.
+    pub delay: i64,
+    /// Keys which may initiate a recovery.
+    pub recovery_keys: Vec<Pubkey>,
+
+    /// When the pending recovery was initiated. 0 if no recovery is pending.
+    pub initiated_at: i64,
+    /// Owners of the [SmartWallet] after the pending recovery.
+    pub new_owners: Vec<Pubkey>,
+    /// Threshold of the [SmartWallet] after the pending recovery.
+    pub new_threshold: u64,
+}
+
+impl Recovery {
+    /// Computes the space a [Recovery] uses.
+    pub fn space(max_recovery_keys: u8, max_owners: u8) -> usize {
+        8 // Anchor discriminator
+            + std::mem::size_of::<Recovery>()
+            + std::mem::size_of::<Pubkey>() * (max_recovery_keys as usize + max_owners as usize)
+    }
+
+    /// Returns true if a recovery is pending.
+    pub fn is_pending(&self) -> bool {
+        self.initiated_at != 0
+    }
+}
+
+/// Owner set which may claim an inactive [SmartWallet].
+///
+/// If no owner has created, approved, or executed a [Transaction] for
+/// [Beneficiary::inactivity_period] seconds, a beneficiary owner may replace
+/// the owners and threshold of the [SmartWallet] with those of the [Beneficiary].
+#[account]
+#[derive(Default, Debug, PartialEq)]
+pub struct Beneficiary {
+    /// The [SmartWallet].
+    pub smart_wallet: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// Time without owner activity after which the [SmartWallet] may be claimed, in seconds.
+    pub inactivity_period: i64,
+    /// Threshold of the [SmartWallet] once claimed.
+    pub threshold: u64,
+    /// Owners of the [SmartWallet] once claimed.
+    pub owners: Vec<Pubkey>,
+}
+
+impl Beneficiary {
+    /// Computes the space a [Beneficiary] uses.
+    pub fn space(max_owners: u8) -> usize {
+        8 // Anchor discriminator
+            + std::mem::size_of::<Beneficiary>()
+            + std::mem::size_of::<Pubkey>() * (max_owners as usize)
+    }
+}
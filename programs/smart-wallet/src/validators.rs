@@ -2,6 +2,78 @@
 
 use crate::*;
 
+/// Validates a set of owners, their weights and permissions, and the threshold.
+///
+/// The owner set must be non-empty and must not contain duplicates.
+/// The weights must either be empty or contain one non-zero weight per owner,
+/// and the permissions must either be empty or contain one valid bitmask per owner.
+/// The threshold must be between 1 and the total weight of the owners
+/// which may approve.
+pub(crate) fn validate_owner_set(
+    owners: &[Pubkey],
+    owner_weights: &[u64],
+    owner_permissions: &[u8],
+    threshold: u64,
+) -> Result<()> {
+    invariant!(!owners.is_empty(), EmptyOwners);
+    for (i, owner) in owners.iter().enumerate() {
+        invariant!(!owners[..i].contains(owner), DuplicateOwner);
+    }
+
+    if !owner_weights.is_empty() {
+        invariant!(owner_weights.len() == owners.len(), InvalidOwnerWeights);
+        invariant!(owner_weights.iter().all(|w| *w > 0), InvalidOwnerWeights);
+    }
+    if !owner_permissions.is_empty() {
+        invariant!(
+            owner_permissions.len() == owners.len(),
+            InvalidOwnerPermissions
+        );
+        invariant!(
+            owner_permissions.iter().all(|p| p & !PERMISSION_ALL == 0),
+            InvalidOwnerPermissions
+        );
+    }
+
+    // only the weight of owners which may approve counts towards the threshold.
+    let mut total_weight: u64 = 0;
+    for index in 0..owners.len() {
+        let permission = owner_permissions
+            .get(index)
+            .copied()
+            .unwrap_or(PERMISSION_ALL);
+        if permission & PERMISSION_APPROVE != 0 {
+            let weight = owner_weights.get(index).copied().unwrap_or(1);
+            total_weight = unwrap_int!(total_weight.checked_add(weight));
+        }
+    }
+
+    invariant!(threshold > 0, ZeroThreshold);
+    invariant!(threshold <= total_weight, InvalidThreshold);
+    Ok(())
+}
+
+/// Counts the distinct `keys` which signed, either as `signer` or as one of
+/// the `remaining_accounts`.
+pub(crate) fn count_signers(
+    keys: &[Pubkey],
+    signer: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> usize {
+    let mut signed = vec![false; keys.len()];
+    for key in std::iter::once(signer).chain(
+        remaining_accounts
+            .iter()
+            .filter(|account| account.is_signer)
+            .map(|account| account.key()),
+    ) {
+        if let Some(index) = keys.iter().position(|k| *k == key) {
+            signed[index] = true;
+        }
+    }
+    signed.iter().filter(|did_sign| **did_sign).count()
+}
+
 impl<'info> Validate<'info> for CreateSmartWallet<'info> {
     fn validate(&self) -> Result<()> {
         Ok(())
@@ -32,14 +104,31 @@ impl<'info> Validate<'info> for ExecuteTransaction<'info> {
             self.transaction.smart_wallet,
             "smart_wallet"
         );
-        invariant!(
-            self.smart_wallet.owner_set_seqno == self.transaction.owner_set_seqno,
-            OwnerSetChanged
-        );
+        invariant!(self.smart_wallet.is_migrated(), WalletNotMigrated);
 
         // Has this been executed already?
         invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
 
+        // Has this been vetoed by the guardian?
+        invariant!(!self.transaction.is_vetoed(), TransactionVetoed);
+        invariant!(!self.transaction.is_rejected(), TransactionRejected);
+        invariant!(!self.transaction.draft, TransactionIsDraft);
+
+        // Paused wallets may only execute transactions which unpause them.
+        if self.smart_wallet.is_paused() {
+            invariant!(
+                self.transaction
+                    .instructions
+                    .iter()
+                    .all(|ix| ix.is_unpause()),
+                WalletPaused
+            );
+        }
+
+        // ensure that the executor is allowed to execute
+        // unless the wallet opts in, this prevents common frontrunning/flash loan attacks
+        self.smart_wallet.check_executor(self.owner.key())?;
+
         let eta = self.transaction.eta;
         let clock = Clock::get()?;
         let current_ts = clock.unix_timestamp;
@@ -54,16 +143,59 @@ impl<'info> Validate<'info> for ExecuteTransaction<'info> {
             );
         }
 
-        // Do we have enough signers to execute the TX?
-        let sig_count = self.transaction.num_signers();
+        // Once execution has started, the remaining instructions were already authorized,
+        // so they may be executed regardless of later changes to the owners or timelock.
+        if self.transaction.executed_count > 0 {
+            return Ok(());
+        }
+
         invariant!(
-            (sig_count as u64) >= self.smart_wallet.threshold,
-            NotEnoughSigners
+            self.smart_wallet.owner_set_seqno == self.transaction.owner_set_seqno,
+            OwnerSetChanged
         );
 
-        // ensure that the owner is a signer
-        // this prevents common frontrunning/flash loan attacks
-        self.smart_wallet.try_owner_index(self.owner.key())?;
+        // Does the ETA still satisfy the timelock?
+        // The minimum delay may have been raised since the transaction was created.
+        if self.smart_wallet.minimum_delay != 0 {
+            match self.smart_wallet.timelock_mode {
+                TimelockMode::Creation => {
+                    invariant!(
+                        eta >= unwrap_int!(self
+                            .transaction
+                            .created_at
+                            .checked_add(self.smart_wallet.minimum_delay)),
+                        InvalidETA
+                    );
+                }
+                TimelockMode::ThresholdReached => {
+                    let threshold_reached_at = unwrap_opt!(
+                        self.smart_wallet.threshold_reached_at(
+                            &self.transaction.signers,
+                            self.transaction.created_at,
+                            self.transaction.threshold_reached_at,
+                            current_ts
+                        )?,
+                        NotEnoughSigners
+                    );
+                    invariant!(
+                        current_ts
+                            >= unwrap_int!(
+                                threshold_reached_at.checked_add(self.smart_wallet.minimum_delay)
+                            ),
+                        TransactionNotReady
+                    );
+                }
+            }
+        }
+
+        // Do we have enough signers to execute the TX?
+        let approval_weight = self
+            .smart_wallet
+            .approval_weight(&self.transaction.signers)?;
+        let threshold = self
+            .smart_wallet
+            .effective_threshold(self.transaction.created_at, current_ts)?;
+        invariant!(approval_weight >= threshold, NotEnoughSigners);
 
         Ok(())
     }
@@ -71,6 +203,7 @@ impl<'info> Validate<'info> for ExecuteTransaction<'info> {
 
 impl<'info> Validate<'info> for OwnerInvokeInstruction<'info> {
     fn validate(&self) -> Result<()> {
+        invariant!(!self.smart_wallet.is_paused(), WalletPaused);
         self.smart_wallet.try_owner_index(self.owner.key())?;
         Ok(())
     }
@@ -14,6 +14,21 @@ export const GOKI_ADDRESSES = {
   TokenSigner: new PublicKey("NFTUJzSHuUCsMMqMRJpB7PmbsaU7Wm51acdPk2FXMLn"),
 };
 
+/**
+ * Permission bits which may be granted to each owner of a smart wallet.
+ */
+export const OWNER_PERMISSIONS = {
+  Propose: 1 << 0,
+  Approve: 1 << 1,
+  Execute: 1 << 2,
+  All: (1 << 0) | (1 << 1) | (1 << 2),
+};
+
+/**
+ * Domain separator of the messages signed to approve transactions off-chain.
+ */
+export const APPROVAL_MESSAGE_DOMAIN = Buffer.from("GokiSmartWalletApproval");
+
 export const GOKI_IDLS = {
   SmartWallet: SmartWalletJSON,
   TokenSigner: TokenSignerJSON,
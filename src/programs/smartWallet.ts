@@ -10,6 +10,10 @@ export type SmartWalletTypes = AnchorTypes<
   {
     smartWallet: SmartWalletData;
     transaction: SmartWalletTransactionData;
+    transactionBuffer: SmartWalletTransactionBufferData;
+    proposerRecord: ProposerRecordData;
+    recovery: RecoveryData;
+    beneficiary: BeneficiaryData;
     subaccountInfo: SubaccountInfoData;
   },
   {
@@ -21,7 +25,11 @@ export type SmartWalletTypes = AnchorTypes<
 type Accounts = SmartWalletTypes["Accounts"];
 export type SmartWalletData = Accounts["SmartWallet"];
 export type SmartWalletTransactionData = Accounts["Transaction"];
+export type SmartWalletTransactionBufferData = Accounts["TransactionBuffer"];
 export type SubaccountInfoData = Accounts["SubaccountInfo"];
+export type ProposerRecordData = Accounts["ProposerRecord"];
+export type RecoveryData = Accounts["Recovery"];
+export type BeneficiaryData = Accounts["Beneficiary"];
 
 export type SmartWalletInstruction = Omit<
   AnchorDefined<SmartWalletIDL>["TXInstruction"],
@@ -36,11 +44,60 @@ export type SmartWalletProgram = SmartWalletTypes["Program"];
 
 export type WalletCreateEvent = SmartWalletEvents["WalletCreateEvent"];
 export type WalletSetOwnersEvent = SmartWalletEvents["WalletSetOwnersEvent"];
+export type WalletAddOwnerEvent = SmartWalletEvents["WalletAddOwnerEvent"];
+export type WalletRemoveOwnerEvent =
+  SmartWalletEvents["WalletRemoveOwnerEvent"];
+export type WalletSwapOwnerEvent = SmartWalletEvents["WalletSwapOwnerEvent"];
 export type WalletChangeThresholdEvent =
   SmartWalletEvents["WalletChangeThresholdEvent"];
+export type WalletSetOwnerWeightsEvent =
+  SmartWalletEvents["WalletSetOwnerWeightsEvent"];
+export type WalletSetOwnerPermissionsEvent =
+  SmartWalletEvents["WalletSetOwnerPermissionsEvent"];
+export type WalletAddProposerEvent =
+  SmartWalletEvents["WalletAddProposerEvent"];
+export type WalletRemoveProposerEvent =
+  SmartWalletEvents["WalletRemoveProposerEvent"];
+export type WalletSetExecutionPolicyEvent =
+  SmartWalletEvents["WalletSetExecutionPolicyEvent"];
+export type WalletSetGuardianEvent =
+  SmartWalletEvents["WalletSetGuardianEvent"];
+export type WalletSetPauseAuthorityEvent =
+  SmartWalletEvents["WalletSetPauseAuthorityEvent"];
+export type WalletPauseEvent = SmartWalletEvents["WalletPauseEvent"];
+export type WalletUnpauseEvent = SmartWalletEvents["WalletUnpauseEvent"];
+export type WalletSetThresholdDecayEvent =
+  SmartWalletEvents["WalletSetThresholdDecayEvent"];
+export type WalletSetTimelockModeEvent =
+  SmartWalletEvents["WalletSetTimelockModeEvent"];
+export type WalletSetGracePeriodEvent =
+  SmartWalletEvents["WalletSetGracePeriodEvent"];
+export type WalletSetMinimumDelayEvent =
+  SmartWalletEvents["WalletSetMinimumDelayEvent"];
 export type TransactionCreateEvent =
   SmartWalletEvents["TransactionCreateEvent"];
+export type TransactionEditEvent = SmartWalletEvents["TransactionEditEvent"];
+export type TransactionFinalizeEvent =
+  SmartWalletEvents["TransactionFinalizeEvent"];
 export type TransactionApproveEvent =
   SmartWalletEvents["TransactionApproveEvent"];
+export type TransactionRejectEvent =
+  SmartWalletEvents["TransactionRejectEvent"];
+export type TransactionCancelEvent =
+  SmartWalletEvents["TransactionCancelEvent"];
+export type TransactionCloseEvent = SmartWalletEvents["TransactionCloseEvent"];
+export type TransactionVetoEvent = SmartWalletEvents["TransactionVetoEvent"];
 export type TransactionExecuteEvent =
   SmartWalletEvents["TransactionExecuteEvent"];
+export type TransactionExecuteStepEvent =
+  SmartWalletEvents["TransactionExecuteStepEvent"];
+export type TransactionBufferExecuteEvent =
+  SmartWalletEvents["TransactionBufferExecuteEvent"];
+export type RecoverySetEvent = SmartWalletEvents["RecoverySetEvent"];
+export type RecoveryInitiateEvent = SmartWalletEvents["RecoveryInitiateEvent"];
+export type RecoveryCancelEvent = SmartWalletEvents["RecoveryCancelEvent"];
+export type RecoveryCompleteEvent = SmartWalletEvents["RecoveryCompleteEvent"];
+export type BeneficiarySetEvent = SmartWalletEvents["BeneficiarySetEvent"];
+export type BeneficiaryRemoveEvent =
+  SmartWalletEvents["BeneficiaryRemoveEvent"];
+export type WalletClaimEvent = SmartWalletEvents["WalletClaimEvent"];
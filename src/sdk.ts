@@ -96,6 +96,8 @@ export class GokiSDK {
     numOwners,
     base = Keypair.generate(),
     delay = new BN(0),
+    gracePeriod = null,
+    ownerWeights = [],
   }: {
     owners: PublicKey[];
     threshold: BN;
@@ -108,6 +110,15 @@ export class GokiSDK {
      * Timelock delay in seconds
      */
     delay?: BN;
+    /**
+     * Seconds after the ETA until a transaction expires.
+     * Defaults to 14 days if not specified.
+     */
+    gracePeriod?: BN | null;
+    /**
+     * Voting weight of each owner. If empty, every owner has a weight of 1.
+     */
+    ownerWeights?: BN[];
   }): Promise<PendingSmartWallet> {
     const [smartWallet, bump] = await findSmartWallet(base.publicKey);
 
@@ -117,6 +128,8 @@ export class GokiSDK {
       owners,
       threshold,
       delay,
+      gracePeriod,
+      ownerWeights,
       {
         accounts: {
           base: base.publicKey,
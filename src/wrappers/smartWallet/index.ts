@@ -1,24 +1,44 @@
 import type { AugmentedProvider } from "@saberhq/solana-contrib";
 import { TransactionEnvelope } from "@saberhq/solana-contrib";
-import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { SystemProgram } from "@solana/web3.js";
+import type {
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import BN from "bn.js";
+import { sha256 } from "js-sha256";
 
+import { APPROVAL_MESSAGE_DOMAIN } from "../../constants";
 import type {
+  BeneficiaryData,
+  RecoveryData,
   SmartWalletData,
   SmartWalletProgram,
+  SmartWalletTransactionBufferData,
   SmartWalletTransactionData,
 } from "../../programs";
 import type { GokiSDK } from "../../sdk";
 import {
+  findBeneficiaryAddress,
+  findNonceTransactionAddress,
   findOwnerInvokerAddress,
+  findProposerRecordAddress,
+  findRecoveryAddress,
   findTransactionAddress,
   findWalletDerivedAddress,
 } from "./pda";
 import type {
   InitSmartWalletWrapperArgs,
   NewTransactionArgs,
+  NewTransactionWithNonceArgs,
+  PendingSmartWalletNonceTransaction,
   PendingSmartWalletTransaction,
+  PendingSmartWalletTransactionBuffer,
 } from "./types";
 
 export * from "./pda";
@@ -64,6 +84,8 @@ export class SmartWalletWrapper {
     payer = this.provider.wallet.publicKey,
     instructions: ixs,
     eta,
+    bounty,
+    bountyWalletIndex = 0,
   }: NewTransactionArgs): Promise<PendingSmartWalletTransaction> {
     const index = (await this.reloadData()).numTransactions.toNumber();
     const [txKey, txBump] = await findTransactionAddress(this.key, index);
@@ -75,7 +97,20 @@ export class SmartWalletWrapper {
       systemProgram: SystemProgram.programId,
     };
     const instructions: TransactionInstruction[] = [];
-    if (eta === undefined) {
+    if (bounty !== undefined) {
+      instructions.push(
+        this.program.instruction.createTransactionWithBounty(
+          txBump,
+          ixs,
+          eta ?? new BN(-1),
+          bounty,
+          new BN(bountyWalletIndex),
+          {
+            accounts,
+          }
+        )
+      );
+    } else if (eta === undefined) {
       instructions.push(
         this.program.instruction.createTransaction(txBump, ixs, {
           accounts,
@@ -101,6 +136,116 @@ export class SmartWalletWrapper {
     };
   }
 
+  /**
+   * Proposes a new transaction whose address is derived from the proposer and
+   * a proposer-chosen nonce, so concurrent proposals never collide.
+   * @returns
+   */
+  async newTransactionWithNonce({
+    proposer = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+    nonce,
+    instructions,
+    eta = new BN(-1),
+  }: NewTransactionWithNonceArgs): Promise<PendingSmartWalletNonceTransaction> {
+    const [txKey, txBump] = await findNonceTransactionAddress(
+      this.key,
+      proposer,
+      nonce
+    );
+    const ix = this.program.instruction.createTransactionWithNonce(
+      txBump,
+      nonce,
+      instructions,
+      eta,
+      {
+        accounts: {
+          smartWallet: this.key,
+          transaction: txKey,
+          proposer,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+    return {
+      transactionKey: txKey,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  /**
+   * Proposes a new transaction as a non-owner allowed by a proposer record.
+   * The transaction starts with zero approvals.
+   * @returns
+   */
+  async newProposerTransaction({
+    proposer = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+    instructions: ixs,
+    eta = new BN(-1),
+  }: NewTransactionArgs): Promise<PendingSmartWalletTransaction> {
+    const index = (await this.reloadData()).numTransactions.toNumber();
+    const [txKey, txBump] = await findTransactionAddress(this.key, index);
+    const [proposerRecord] = await findProposerRecordAddress(
+      this.key,
+      proposer
+    );
+    const ix = this.program.instruction.createProposerTransaction(
+      txBump,
+      ixs,
+      eta,
+      {
+        accounts: {
+          smartWallet: this.key,
+          transaction: txKey,
+          proposerRecord,
+          proposer,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return {
+      transactionKey: txKey,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+      index,
+    };
+  }
+
+  /**
+   * Proposes a new draft transaction, whose instructions may be edited by the
+   * proposer until it is finalized.
+   * @returns
+   */
+  async newDraftTransaction({
+    proposer = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+    instructions: ixs,
+  }: Omit<
+    NewTransactionArgs,
+    "eta" | "bounty" | "bountyWalletIndex"
+  >): Promise<PendingSmartWalletTransaction> {
+    const index = (await this.reloadData()).numTransactions.toNumber();
+    const [txKey, txBump] = await findTransactionAddress(this.key, index);
+    const ix = this.program.instruction.createDraftTransaction(txBump, ixs, {
+      accounts: {
+        smartWallet: this.key,
+        transaction: txKey,
+        proposer,
+        payer,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return {
+      transactionKey: txKey,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+      index,
+    };
+  }
+
   /**
    * Creates a new transaction from an envelope.
    * @returns
@@ -153,200 +298,808 @@ export class SmartWalletWrapper {
   }
 
   /**
-   * Executes a transaction as the Smart Wallet.
+   * Withdraws the approval of a transaction.
    */
-  async executeTransaction({
-    transactionKey,
-    owner = this.provider.wallet.publicKey,
-  }: {
-    transactionKey: PublicKey;
-    owner?: PublicKey;
-  }): Promise<TransactionEnvelope> {
-    const ix = this.program.instruction.executeTransaction(
-      await this._fetchExecuteTransactionContext({ transactionKey, owner })
-    );
-    return new TransactionEnvelope(this.provider, [ix]);
+  unapproveTransaction(
+    transactionKey: PublicKey,
+    owner: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.unapprove({
+        accounts: {
+          smartWallet: this.key,
+          transaction: transactionKey,
+          owner,
+        },
+      }),
+    ]);
   }
 
   /**
-   * Finds the derived wallet address and bump of a given index.
-   * @param index
-   * @returns
+   * Approves a transaction, failing unless it hashes to `instructionsHash`.
    */
-  async findWalletDerivedAddress(index: number): Promise<[PublicKey, number]> {
-    return await findWalletDerivedAddress(this.key, index);
+  approveTransactionWithHash(
+    transactionKey: PublicKey,
+    instructionsHash: Buffer,
+    owner: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.approveWithHash([...instructionsHash], {
+        accounts: {
+          smartWallet: this.key,
+          transaction: transactionKey,
+          owner,
+        },
+      }),
+    ]);
   }
 
   /**
-   * Finds the owner invoker address and bump of a given index.
-   * @param index
-   * @returns
+   * Computes the canonical hash of a transaction: its instructions,
+   * ETA, and bounty.
    */
-  async findOwnerInvokerAddress(index: number): Promise<[PublicKey, number]> {
-    return await findOwnerInvokerAddress(this.key, index);
-  }
-
-  private async _fetchExecuteTransactionContext({
-    transactionKey,
-    owner = this.provider.wallet.publicKey,
-    walletDerivedAddress = null,
-  }: {
-    transactionKey: PublicKey;
-    owner?: PublicKey;
-    walletDerivedAddress?: PublicKey | null;
-  }) {
-    const data = await this.fetchTransaction(transactionKey);
-    return {
-      accounts: {
-        smartWallet: this.key,
-        transaction: transactionKey,
-        owner,
-      },
-      remainingAccounts: data.instructions.flatMap((ix) => [
-        {
-          pubkey: ix.programId,
-          isSigner: false,
-          isWritable: false,
-        },
-        ...ix.keys.map((k) => {
-          if (
-            k.isSigner &&
-            ((walletDerivedAddress && k.pubkey.equals(walletDerivedAddress)) ||
-              k.pubkey.equals(this.key))
-          ) {
-            return {
-              ...k,
-              isSigner: false,
-            };
-          }
-          return k;
-        }),
-      ]),
-    };
+  hashInstructions({
+    instructions,
+    eta,
+    bounty,
+    bountyWalletIndex,
+  }: Pick<
+    SmartWalletTransactionData,
+    "instructions" | "eta" | "bounty" | "bountyWalletIndex"
+  >): Buffer {
+    const numInstructions = Buffer.alloc(4);
+    numInstructions.writeUInt32LE(instructions.length);
+    return Buffer.from(
+      sha256.arrayBuffer(
+        Buffer.concat([
+          numInstructions,
+          ...instructions.map((ix) =>
+            this.program.coder.types.encode("TXInstruction", ix)
+          ),
+          eta.toTwos(64).toArrayLike(Buffer, "le", 8),
+          bounty.toArrayLike(Buffer, "le", 8),
+          bountyWalletIndex.toArrayLike(Buffer, "le", 8),
+        ])
+      )
+    );
   }
 
   /**
-   * Executes a transaction using a wallet-derived address.
+   * Computes the message an owner signs to approve a transaction off-chain.
    */
-  async executeTransactionDerived({
-    transactionKey,
-    walletIndex,
-    owner = this.provider.wallet.publicKey,
-  }: {
-    transactionKey: PublicKey;
-    walletIndex: number;
-    owner?: PublicKey;
-  }): Promise<TransactionEnvelope> {
-    const [walletDerivedAddress, walletBump] =
-      await this.findWalletDerivedAddress(walletIndex);
-    const ix = this.program.instruction.executeTransactionDerived(
-      new BN(walletIndex),
-      walletBump,
-      await this._fetchExecuteTransactionContext({
-        transactionKey,
-        owner,
-        walletDerivedAddress,
-      })
+  async approvalMessage(transactionKey: PublicKey): Promise<Buffer> {
+    const txData = await this.fetchTransaction(transactionKey);
+    const ownerSetSeqno = Buffer.alloc(4);
+    ownerSetSeqno.writeUInt32LE(txData.ownerSetSeqno);
+    return Buffer.from(
+      sha256.arrayBuffer(
+        Buffer.concat([
+          APPROVAL_MESSAGE_DOMAIN,
+          txData.smartWallet.toBuffer(),
+          transactionKey.toBuffer(),
+          txData.index.toArrayLike(Buffer, "le", 8),
+          ownerSetSeqno,
+          Buffer.from(txData.instructionsHash),
+        ])
+      )
     );
-    return new TransactionEnvelope(this.provider, [ix]);
   }
 
   /**
-   * Executes a transaction using an owner invoker address.
+   * Approves a transaction on behalf of the owners whose signatures of its
+   * approval message are verified by the given Ed25519 program instructions.
    */
-  async ownerInvokeInstruction({
-    instruction,
-    index,
-    owner = this.provider.wallet.publicKey,
-  }: {
-    instruction: TransactionInstruction;
-    index: number;
-    owner?: PublicKey;
-  }): Promise<TransactionEnvelope> {
-    const [invokerAddress, invokerBump] = await this.findOwnerInvokerAddress(
-      index
-    );
-    const ix = this.program.instruction.ownerInvokeInstruction(
-      new BN(index),
-      invokerBump,
-      instruction,
-      {
+  approveWithSignatures(
+    transactionKey: PublicKey,
+    ed25519Instructions: TransactionInstruction[]
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      ...ed25519Instructions,
+      this.program.instruction.approveWithSignatures({
         accounts: {
           smartWallet: this.key,
-          owner,
+          transaction: transactionKey,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         },
-        remainingAccounts: [
-          {
-            pubkey: instruction.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-          ...instruction.keys.map((k) => {
-            if (k.isSigner && invokerAddress.equals(k.pubkey)) {
-              return {
-                ...k,
-                isSigner: false,
-              };
-            }
-            return k;
-          }),
-        ],
-      }
-    );
-    return new TransactionEnvelope(this.provider, [ix]);
+      }),
+    ]);
   }
 
   /**
-   * Executes a transaction using an owner invoker address.
+   * Rejects a transaction.
    */
-  async ownerInvokeInstructionV2({
-    instruction,
-    index,
-    owner = this.provider.wallet.publicKey,
-  }: {
-    instruction: TransactionInstruction;
-    index: number;
-    owner?: PublicKey;
-  }): Promise<TransactionEnvelope> {
-    const [invokerAddress, invokerBump] = await this.findOwnerInvokerAddress(
-      index
-    );
-    const ix = this.program.instruction.ownerInvokeInstructionV2(
-      new BN(index),
-      invokerBump,
-      invokerAddress,
-      instruction.data,
-      {
+  rejectTransaction(
+    transactionKey: PublicKey,
+    owner: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.reject({
         accounts: {
           smartWallet: this.key,
+          transaction: transactionKey,
           owner,
         },
-        remainingAccounts: [
-          {
-            pubkey: instruction.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-          ...instruction.keys.map((k) => {
-            if (k.isSigner && invokerAddress.equals(k.pubkey)) {
-              return {
-                ...k,
-                isSigner: false,
-              };
-            }
-            return k;
-          }),
-        ],
-      }
-    );
-    return new TransactionEnvelope(this.provider, [ix]);
+      }),
+    ]);
   }
 
   /**
-   * setOwners
+   * Vetoes a transaction on behalf of the guardian.
    */
-  setOwners(owners: PublicKey[]): TransactionEnvelope {
-    const ix = this.program.instruction.setOwners(owners, {
+  vetoTransaction(
+    transactionKey: PublicKey,
+    guardian: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.vetoTransaction({
+        accounts: {
+          smartWallet: this.key,
+          transaction: transactionKey,
+          guardian,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Vetoes a transaction buffer on behalf of the guardian.
+   */
+  vetoTransactionBuffer(
+    bufferKey: PublicKey,
+    guardian: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.vetoTransactionBuffer({
+        accounts: {
+          smartWallet: this.key,
+          buffer: bufferKey,
+          guardian,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Cancels a transaction, refunding its rent to the payer.
+   */
+  async cancelTransaction(
+    transactionKey: PublicKey,
+    proposer: PublicKey = this.provider.wallet.publicKey
+  ): Promise<TransactionEnvelope> {
+    const data = await this.fetchTransaction(transactionKey);
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.cancelTransaction({
+        accounts: {
+          smartWallet: this.key,
+          transaction: transactionKey,
+          proposer,
+          payer: data.payer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Appends an instruction to a draft transaction.
+   */
+  appendDraftInstruction(
+    transactionKey: PublicKey,
+    ix: TransactionInstruction,
+    proposer: PublicKey = this.provider.wallet.publicKey,
+    payer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.appendDraftInstruction(ix, {
+        accounts: this._editDraftAccounts(transactionKey, proposer, payer),
+      }),
+    ]);
+  }
+
+  /**
+   * Replaces the instruction at `index` of a draft transaction.
+   */
+  replaceDraftInstruction(
+    transactionKey: PublicKey,
+    index: number,
+    ix: TransactionInstruction,
+    proposer: PublicKey = this.provider.wallet.publicKey,
+    payer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.replaceDraftInstruction(index, ix, {
+        accounts: this._editDraftAccounts(transactionKey, proposer, payer),
+      }),
+    ]);
+  }
+
+  /**
+   * Removes the instruction at `index` of a draft transaction.
+   */
+  removeDraftInstruction(
+    transactionKey: PublicKey,
+    index: number,
+    proposer: PublicKey = this.provider.wallet.publicKey,
+    payer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.removeDraftInstruction(index, {
+        accounts: this._editDraftAccounts(transactionKey, proposer, payer),
+      }),
+    ]);
+  }
+
+  private _editDraftAccounts(
+    transactionKey: PublicKey,
+    proposer: PublicKey,
+    payer: PublicKey
+  ) {
+    return {
+      smartWallet: this.key,
+      transaction: transactionKey,
+      proposer,
+      payer,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  /**
+   * Finalizes a draft transaction, opening it for approvals.
+   */
+  finalizeTransaction(
+    transactionKey: PublicKey,
+    eta: BN = new BN(-1),
+    proposer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.finalizeTransaction(eta, {
+        accounts: {
+          smartWallet: this.key,
+          transaction: transactionKey,
+          proposer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Closes an executed or stale transaction, refunding its rent to the payer.
+   */
+  async closeTransaction(
+    transactionKey: PublicKey
+  ): Promise<TransactionEnvelope> {
+    const data = await this.fetchTransaction(transactionKey);
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.closeTransaction({
+        accounts: {
+          smartWallet: this.key,
+          transaction: transactionKey,
+          payer: data.payer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Closes a transaction created before the payer was recorded,
+   * refunding its rent to its proposer.
+   */
+  closeLegacyTransaction(
+    transactionKey: PublicKey,
+    proposer: PublicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.closeLegacyTransaction({
+        accounts: {
+          smartWallet: this.key,
+          transaction: transactionKey,
+          proposer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Executes a transaction as the Smart Wallet.
+   */
+  async executeTransaction({
+    transactionKey,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    transactionKey: PublicKey;
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const ix = this.program.instruction.executeTransaction(
+      await this._fetchExecuteTransactionContext({ transactionKey, owner })
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Executes the next `numInstructions` instructions of a transaction as the
+   * Smart Wallet. The transaction is marked as executed once its last
+   * instruction has been executed.
+   */
+  async executeTransactionStep({
+    transactionKey,
+    numInstructions,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    transactionKey: PublicKey;
+    numInstructions: number;
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const ix = this.program.instruction.executeTransactionStep(
+      numInstructions,
+      await this._fetchExecuteTransactionContext({
+        transactionKey,
+        owner,
+        numInstructions,
+      })
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Allocates and initializes a new transaction buffer.
+   */
+  async initTransactionBuffer({
+    space,
+    proposer = this.provider.wallet.publicKey,
+    eta = new BN(-1),
+    buffer = Keypair.generate(),
+  }: {
+    /**
+     * Number of bytes to allocate for the buffer.
+     */
+    space: number;
+    proposer?: PublicKey;
+    eta?: BN;
+    buffer?: Signer;
+  }): Promise<PendingSmartWalletTransactionBuffer> {
+    const lamports =
+      await this.provider.connection.getMinimumBalanceForRentExemption(space);
+    return {
+      bufferKey: buffer.publicKey,
+      tx: new TransactionEnvelope(
+        this.provider,
+        [
+          SystemProgram.createAccount({
+            fromPubkey: this.provider.wallet.publicKey,
+            newAccountPubkey: buffer.publicKey,
+            space,
+            lamports,
+            programId: this.program.programId,
+          }),
+          this.program.instruction.initTransactionBuffer(eta, {
+            accounts: {
+              smartWallet: this.key,
+              buffer: buffer.publicKey,
+              proposer,
+            },
+          }),
+        ],
+        [buffer]
+      ),
+    };
+  }
+
+  /**
+   * Appends instructions to a bundle of a transaction buffer.
+   */
+  writeTransactionBuffer({
+    bufferKey,
+    bundleIndex,
+    instructions,
+    proposer = this.provider.wallet.publicKey,
+  }: {
+    bufferKey: PublicKey;
+    bundleIndex: number;
+    instructions: TransactionInstruction[];
+    proposer?: PublicKey;
+  }): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.writeTransactionBuffer(
+        bundleIndex,
+        instructions,
+        {
+          accounts: {
+            buffer: bufferKey,
+            proposer,
+          },
+        }
+      ),
+    ]);
+  }
+
+  /**
+   * Finalizes a transaction buffer.
+   */
+  finalizeTransactionBuffer(
+    bufferKey: PublicKey,
+    proposer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.finalizeTransactionBuffer({
+        accounts: {
+          buffer: bufferKey,
+          proposer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Approves a transaction buffer.
+   */
+  approveTransactionBuffer(
+    bufferKey: PublicKey,
+    owner: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.approveTransactionBuffer({
+        accounts: {
+          smartWallet: this.key,
+          buffer: bufferKey,
+          owner,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * fetchTransactionBuffer
+   */
+  async fetchTransactionBuffer(
+    key: PublicKey
+  ): Promise<SmartWalletTransactionBufferData> {
+    return await this.program.account.transactionBuffer.fetch(key);
+  }
+
+  /**
+   * Executes a bundle of a transaction buffer as the Smart Wallet.
+   */
+  async executeBufferBundle({
+    bufferKey,
+    bundleIndex,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    bufferKey: PublicKey;
+    bundleIndex: number;
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const data = await this.fetchTransactionBuffer(bufferKey);
+    const bundle = data.bundles[bundleIndex];
+    if (!bundle) {
+      throw new Error(`bundle ${bundleIndex} not found`);
+    }
+    const ix = this.program.instruction.executeBufferBundle(bundleIndex, {
+      accounts: {
+        smartWallet: this.key,
+        buffer: bufferKey,
+        owner,
+      },
+      remainingAccounts: bundle.instructions.flatMap((ix) => [
+        {
+          pubkey: ix.programId,
+          isSigner: false,
+          isWritable: false,
+        },
+        ...ix.keys.map((k) =>
+          k.isSigner && k.pubkey.equals(this.key)
+            ? { ...k, isSigner: false }
+            : k
+        ),
+      ]),
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Closes a transaction buffer, refunding its rent to its proposer.
+   */
+  async closeTransactionBuffer(
+    bufferKey: PublicKey
+  ): Promise<TransactionEnvelope> {
+    const data = await this.fetchTransactionBuffer(bufferKey);
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.closeTransactionBuffer({
+        accounts: {
+          smartWallet: this.key,
+          buffer: bufferKey,
+          proposer: data.proposer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Finds the derived wallet address and bump of a given index.
+   * @param index
+   * @returns
+   */
+  async findWalletDerivedAddress(index: number): Promise<[PublicKey, number]> {
+    return await findWalletDerivedAddress(this.key, index);
+  }
+
+  /**
+   * Finds the owner invoker address and bump of a given index.
+   * @param index
+   * @returns
+   */
+  async findOwnerInvokerAddress(index: number): Promise<[PublicKey, number]> {
+    return await findOwnerInvokerAddress(this.key, index);
+  }
+
+  private async _fetchExecuteTransactionContext({
+    transactionKey,
+    owner = this.provider.wallet.publicKey,
+    walletDerivedAddress = null,
+    numInstructions,
+  }: {
+    transactionKey: PublicKey;
+    owner?: PublicKey;
+    walletDerivedAddress?: PublicKey | null;
+    /**
+     * If set, only the accounts of the next `numInstructions` instructions
+     * are passed.
+     */
+    numInstructions?: number;
+  }) {
+    const data = await this.fetchTransaction(transactionKey);
+    const start = data.executedCount.toNumber();
+    const end =
+      numInstructions === undefined
+        ? data.instructions.length
+        : Math.min(start + numInstructions, data.instructions.length);
+    const bountyAccounts =
+      data.bounty.isZero() || end < data.instructions.length
+        ? []
+        : [
+            {
+              pubkey: (
+                await this.findWalletDerivedAddress(
+                  data.bountyWalletIndex.toNumber()
+                )
+              )[0],
+              isSigner: false,
+              isWritable: true,
+            },
+            {
+              pubkey: owner,
+              isSigner: false,
+              isWritable: true,
+            },
+            {
+              pubkey: SystemProgram.programId,
+              isSigner: false,
+              isWritable: false,
+            },
+          ];
+    return {
+      accounts: {
+        smartWallet: this.key,
+        transaction: transactionKey,
+        owner,
+      },
+      remainingAccounts: [
+        ...data.instructions.slice(start, end).flatMap((ix) => [
+          {
+            pubkey: ix.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          ...ix.keys.map((k) => {
+            if (
+              k.isSigner &&
+              ((walletDerivedAddress &&
+                k.pubkey.equals(walletDerivedAddress)) ||
+                k.pubkey.equals(this.key))
+            ) {
+              return {
+                ...k,
+                isSigner: false,
+              };
+            }
+            return k;
+          }),
+        ]),
+        ...bountyAccounts,
+      ],
+    };
+  }
+
+  /**
+   * Executes a transaction using a wallet-derived address.
+   */
+  async executeTransactionDerived({
+    transactionKey,
+    walletIndex,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    transactionKey: PublicKey;
+    walletIndex: number;
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [walletDerivedAddress, walletBump] =
+      await this.findWalletDerivedAddress(walletIndex);
+    const ix = this.program.instruction.executeTransactionDerived(
+      new BN(walletIndex),
+      walletBump,
+      await this._fetchExecuteTransactionContext({
+        transactionKey,
+        owner,
+        walletDerivedAddress,
+      })
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Executes a transaction using an owner invoker address.
+   */
+  async ownerInvokeInstruction({
+    instruction,
+    index,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    instruction: TransactionInstruction;
+    index: number;
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [invokerAddress, invokerBump] = await this.findOwnerInvokerAddress(
+      index
+    );
+    const ix = this.program.instruction.ownerInvokeInstruction(
+      new BN(index),
+      invokerBump,
+      instruction,
+      {
+        accounts: {
+          smartWallet: this.key,
+          owner,
+        },
+        remainingAccounts: [
+          {
+            pubkey: instruction.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          ...instruction.keys.map((k) => {
+            if (k.isSigner && invokerAddress.equals(k.pubkey)) {
+              return {
+                ...k,
+                isSigner: false,
+              };
+            }
+            return k;
+          }),
+        ],
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Executes a transaction using an owner invoker address.
+   */
+  async ownerInvokeInstructionV2({
+    instruction,
+    index,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    instruction: TransactionInstruction;
+    index: number;
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [invokerAddress, invokerBump] = await this.findOwnerInvokerAddress(
+      index
+    );
+    const ix = this.program.instruction.ownerInvokeInstructionV2(
+      new BN(index),
+      invokerBump,
+      invokerAddress,
+      instruction.data,
+      {
+        accounts: {
+          smartWallet: this.key,
+          owner,
+        },
+        remainingAccounts: [
+          {
+            pubkey: instruction.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          ...instruction.keys.map((k) => {
+            if (k.isSigner && invokerAddress.equals(k.pubkey)) {
+              return {
+                ...k,
+                isSigner: false,
+              };
+            }
+            return k;
+          }),
+        ],
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Grows the smart wallet so that it can hold up to `maxOwners` owners.
+   */
+  resizeSmartWallet(
+    maxOwners: number,
+    payer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    const ix = this.program.instruction.resizeSmartWallet(maxOwners, {
+      accounts: {
+        smartWallet: this.key,
+        payer,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Migrates a smart wallet created before owner weights were added
+   * to the current account layout.
+   */
+  migrateSmartWallet(): TransactionEnvelope {
+    const ix = this.program.instruction.migrateSmartWallet({
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * setOwners
+   */
+  setOwners(owners: PublicKey[]): TransactionEnvelope {
+    const ix = this.program.instruction.setOwners(owners, {
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * addOwner
+   */
+  addOwner(owner: PublicKey): TransactionEnvelope {
+    const ix = this.program.instruction.addOwner(owner, {
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * removeOwner
+   */
+  removeOwner(owner: PublicKey, threshold: number): TransactionEnvelope {
+    const ix = this.program.instruction.removeOwner(
+      owner,
+      new BN(threshold),
+      {
+        accounts: {
+          smartWallet: this.key,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * swapOwner
+   */
+  swapOwner(oldOwner: PublicKey, newOwner: PublicKey): TransactionEnvelope {
+    const ix = this.program.instruction.swapOwner(oldOwner, newOwner, {
       accounts: {
         smartWallet: this.key,
       },
@@ -366,6 +1119,468 @@ export class SmartWalletWrapper {
     return new TransactionEnvelope(this.provider, [ix]);
   }
 
+  /**
+   * setOwnerWeights
+   */
+  setOwnerWeights(ownerWeights: BN[]): TransactionEnvelope {
+    const ix = this.program.instruction.setOwnerWeights(ownerWeights, {
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * setOwnerPermissions
+   */
+  setOwnerPermissions(ownerPermissions: number[]): TransactionEnvelope {
+    const ix = this.program.instruction.setOwnerPermissions(
+      Buffer.from(ownerPermissions),
+      {
+        accounts: {
+          smartWallet: this.key,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Allows a non-owner to propose transactions.
+   */
+  async addProposer(
+    proposer: PublicKey,
+    payer: PublicKey = this.provider.wallet.publicKey
+  ): Promise<TransactionEnvelope> {
+    const [proposerRecord] = await findProposerRecordAddress(
+      this.key,
+      proposer
+    );
+    const ix = this.program.instruction.addProposer(proposer, {
+      accounts: {
+        smartWallet: this.key,
+        proposerRecord,
+        payer,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Revokes an allowed proposer, refunding the rent of its record.
+   */
+  async removeProposer(
+    proposer: PublicKey,
+    receiver: PublicKey = this.provider.wallet.publicKey
+  ): Promise<TransactionEnvelope> {
+    const [proposerRecord] = await findProposerRecordAddress(
+      this.key,
+      proposer
+    );
+    const ix = this.program.instruction.removeProposer({
+      accounts: {
+        smartWallet: this.key,
+        proposerRecord,
+        receiver,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Sets who may execute approved transactions.
+   */
+  setExecutionPolicy(
+    policy: "owners" | "keepers" | "anyone",
+    keepers: PublicKey[] = []
+  ): TransactionEnvelope {
+    const ix = this.program.instruction.setExecutionPolicy(
+      {
+        [policy]: {},
+      },
+      keepers,
+      {
+        accounts: {
+          smartWallet: this.key,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * setGuardian
+   */
+  setGuardian(guardian: PublicKey): TransactionEnvelope {
+    const ix = this.program.instruction.setGuardian(guardian, {
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * setPauseAuthority
+   */
+  setPauseAuthority(
+    pauseAuthority: PublicKey,
+    pauseThreshold = 0
+  ): TransactionEnvelope {
+    const ix = this.program.instruction.setPauseAuthority(
+      pauseAuthority,
+      new BN(pauseThreshold),
+      {
+        accounts: {
+          smartWallet: this.key,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Pauses the smart wallet as the pause authority, or as several owners
+   * signing together.
+   */
+  pause({
+    authority = this.provider.wallet.publicKey,
+    owners = [],
+  }: {
+    authority?: PublicKey;
+    /**
+     * Additional owners which sign the pause.
+     */
+    owners?: PublicKey[];
+  } = {}): TransactionEnvelope {
+    const ix = this.program.instruction.pause({
+      accounts: {
+        smartWallet: this.key,
+        authority,
+      },
+      remainingAccounts: owners.map((owner) => ({
+        pubkey: owner,
+        isSigner: true,
+        isWritable: false,
+      })),
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * unpause
+   */
+  unpause(): TransactionEnvelope {
+    const ix = this.program.instruction.unpause({
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Creates the recovery of the smart wallet.
+   */
+  async createRecovery({
+    recoveryKeys,
+    threshold,
+    delay,
+    maxRecoveryKeys = recoveryKeys.length,
+    maxOwners = this.data?.owners.length ?? 0,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    recoveryKeys: PublicKey[];
+    threshold: BN;
+    delay: BN;
+    maxRecoveryKeys?: number;
+    /**
+     * Maximum number of owners the recovery can propose.
+     */
+    maxOwners?: number;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [recovery] = await findRecoveryAddress(this.key);
+    const ix = this.program.instruction.createRecovery(
+      maxRecoveryKeys,
+      maxOwners,
+      recoveryKeys,
+      threshold,
+      delay,
+      {
+        accounts: {
+          smartWallet: this.key,
+          recovery,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Changes the recovery keys, threshold, and delay of the recovery.
+   */
+  async setRecovery({
+    recoveryKeys,
+    threshold,
+    delay,
+  }: {
+    recoveryKeys: PublicKey[];
+    threshold: BN;
+    delay: BN;
+  }): Promise<TransactionEnvelope> {
+    const [recovery] = await findRecoveryAddress(this.key);
+    const ix = this.program.instruction.setRecovery(
+      recoveryKeys,
+      threshold,
+      delay,
+      {
+        accounts: {
+          smartWallet: this.key,
+          recovery,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Initiates a recovery as several recovery keys signing together.
+   */
+  async initiateRecovery({
+    newOwners,
+    newThreshold,
+    initiator = this.provider.wallet.publicKey,
+    recoveryKeys = [],
+  }: {
+    newOwners: PublicKey[];
+    newThreshold: BN;
+    initiator?: PublicKey;
+    /**
+     * Additional recovery keys which sign the initiation.
+     */
+    recoveryKeys?: PublicKey[];
+  }): Promise<TransactionEnvelope> {
+    const [recovery] = await findRecoveryAddress(this.key);
+    const ix = this.program.instruction.initiateRecovery(
+      newOwners,
+      newThreshold,
+      {
+        accounts: {
+          smartWallet: this.key,
+          recovery,
+          initiator,
+        },
+        remainingAccounts: recoveryKeys.map((key) => ({
+          pubkey: key,
+          isSigner: true,
+          isWritable: false,
+        })),
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Cancels a pending recovery as one of the current owners.
+   */
+  async cancelRecovery(
+    owner: PublicKey = this.provider.wallet.publicKey
+  ): Promise<TransactionEnvelope> {
+    const [recovery] = await findRecoveryAddress(this.key);
+    const ix = this.program.instruction.cancelRecovery({
+      accounts: {
+        smartWallet: this.key,
+        recovery,
+        owner,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Completes a pending recovery once its delay has passed.
+   */
+  async completeRecovery(): Promise<TransactionEnvelope> {
+    const [recovery] = await findRecoveryAddress(this.key);
+    const ix = this.program.instruction.completeRecovery({
+      accounts: {
+        smartWallet: this.key,
+        recovery,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Fetches the recovery of the smart wallet.
+   */
+  async fetchRecovery(): Promise<RecoveryData | null> {
+    const [recovery] = await findRecoveryAddress(this.key);
+    return await this.program.account.recovery.fetchNullable(recovery);
+  }
+
+  /**
+   * Registers the beneficiary which may claim the smart wallet once it is
+   * inactive.
+   */
+  async createBeneficiary({
+    owners,
+    threshold,
+    inactivityPeriod,
+    maxOwners = owners.length,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    owners: PublicKey[];
+    threshold: BN;
+    inactivityPeriod: BN;
+    maxOwners?: number;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [beneficiary] = await findBeneficiaryAddress(this.key);
+    const ix = this.program.instruction.createBeneficiary(
+      maxOwners,
+      owners,
+      threshold,
+      inactivityPeriod,
+      {
+        accounts: {
+          smartWallet: this.key,
+          beneficiary,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Changes the beneficiary of the smart wallet.
+   */
+  async setBeneficiary({
+    owners,
+    threshold,
+    inactivityPeriod,
+  }: {
+    owners: PublicKey[];
+    threshold: BN;
+    inactivityPeriod: BN;
+  }): Promise<TransactionEnvelope> {
+    const [beneficiary] = await findBeneficiaryAddress(this.key);
+    const ix = this.program.instruction.setBeneficiary(
+      owners,
+      threshold,
+      inactivityPeriod,
+      {
+        accounts: {
+          smartWallet: this.key,
+          beneficiary,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Removes the beneficiary of the smart wallet, refunding its rent.
+   */
+  async removeBeneficiary(
+    receiver: PublicKey = this.provider.wallet.publicKey
+  ): Promise<TransactionEnvelope> {
+    const [beneficiary] = await findBeneficiaryAddress(this.key);
+    const ix = this.program.instruction.removeBeneficiary({
+      accounts: {
+        smartWallet: this.key,
+        beneficiary,
+        receiver,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Claims the smart wallet as a beneficiary owner once it is inactive.
+   */
+  async claimInactiveWallet(
+    claimant: PublicKey = this.provider.wallet.publicKey
+  ): Promise<TransactionEnvelope> {
+    const [beneficiary] = await findBeneficiaryAddress(this.key);
+    const ix = this.program.instruction.claimInactiveWallet({
+      accounts: {
+        smartWallet: this.key,
+        beneficiary,
+        claimant,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Fetches the beneficiary of the smart wallet.
+   */
+  async fetchBeneficiary(): Promise<BeneficiaryData | null> {
+    const [beneficiary] = await findBeneficiaryAddress(this.key);
+    return await this.program.account.beneficiary.fetchNullable(beneficiary);
+  }
+
+  /**
+   * setThresholdDecay
+   */
+  setThresholdDecay(decayPeriod: BN, floor: BN): TransactionEnvelope {
+    const ix = this.program.instruction.setThresholdDecay(decayPeriod, floor, {
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Sets when the minimum delay starts counting.
+   */
+  setTimelockMode(mode: "creation" | "thresholdReached"): TransactionEnvelope {
+    const ix = this.program.instruction.setTimelockMode(
+      {
+        [mode]: {},
+      },
+      {
+        accounts: {
+          smartWallet: this.key,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * setGracePeriod
+   */
+  setGracePeriod(gracePeriod: BN): TransactionEnvelope {
+    const ix = this.program.instruction.setGracePeriod(gracePeriod, {
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * setMinimumDelay
+   */
+  setMinimumDelay(minimumDelay: BN): TransactionEnvelope {
+    const ix = this.program.instruction.setMinimumDelay(minimumDelay, {
+      accounts: {
+        smartWallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
   /**
    * Loads a SmartWallet
    */
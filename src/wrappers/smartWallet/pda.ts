@@ -2,6 +2,7 @@ import { utils } from "@project-serum/anchor";
 import { getProgramAddress } from "@saberhq/solana-contrib";
 import { u64 } from "@saberhq/token-utils";
 import { PublicKey } from "@solana/web3.js";
+import type BN from "bn.js";
 
 import { GOKI_ADDRESSES } from "../../constants";
 
@@ -28,6 +29,29 @@ export const findTransactionAddress = async (
   );
 };
 
+/**
+ * Finds the address of a transaction created with a proposer-chosen nonce.
+ * @param smartWallet
+ * @param proposer
+ * @param nonce
+ * @returns
+ */
+export const findNonceTransactionAddress = async (
+  smartWallet: PublicKey,
+  proposer: PublicKey,
+  nonce: BN
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("GokiTransaction"),
+      smartWallet.toBuffer(),
+      proposer.toBuffer(),
+      nonce.toArrayLike(Buffer, "le", 8),
+    ],
+    GOKI_ADDRESSES.SmartWallet
+  );
+};
+
 /**
  * Finds a derived address of a Smart Wallet.
  * @param smartWallet
@@ -83,6 +107,54 @@ export const findSubaccountInfoAddress = async (
   );
 };
 
+/**
+ * Finds the proposer record address of an allowed proposer of a smart wallet.
+ * @param smartWallet
+ * @param proposer
+ * @returns
+ */
+export const findProposerRecordAddress = async (
+  smartWallet: PublicKey,
+  proposer: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("GokiProposerRecord"),
+      smartWallet.toBuffer(),
+      proposer.toBuffer(),
+    ],
+    GOKI_ADDRESSES.SmartWallet
+  );
+};
+
+/**
+ * Finds the recovery address of a smart wallet.
+ * @param smartWallet
+ * @returns
+ */
+export const findRecoveryAddress = async (
+  smartWallet: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("GokiRecovery"), smartWallet.toBuffer()],
+    GOKI_ADDRESSES.SmartWallet
+  );
+};
+
+/**
+ * Finds the beneficiary address of a smart wallet.
+ * @param smartWallet
+ * @returns
+ */
+export const findBeneficiaryAddress = async (
+  smartWallet: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("GokiBeneficiary"), smartWallet.toBuffer()],
+    GOKI_ADDRESSES.SmartWallet
+  );
+};
+
 export const getSmartWalletAddress = (base: PublicKey): PublicKey => {
   return getProgramAddress(
     [utils.bytes.utf8.encode("GokiSmartWallet"), base.toBuffer()],
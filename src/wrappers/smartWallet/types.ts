@@ -32,6 +32,42 @@ export type PendingSmartWalletTransaction = {
   readonly index: number;
 };
 
+export type PendingSmartWalletTransactionBuffer = {
+  /**
+   * Pubkey of the created transaction buffer.
+   */
+  readonly bufferKey: PublicKey;
+  /**
+   * Transaction to allocate and initialize the transaction buffer.
+   */
+  readonly tx: TransactionEnvelope;
+};
+
+export type PendingSmartWalletNonceTransaction = Omit<
+  PendingSmartWalletTransaction,
+  "index"
+>;
+
+export interface NewTransactionWithNonceArgs {
+  readonly proposer?: PublicKey;
+  /**
+   * Payer of the created transaction.
+   */
+  readonly payer?: PublicKey;
+  /**
+   * Proposer-chosen nonce from which the transaction address is derived.
+   */
+  readonly nonce: BN;
+  /**
+   * Instructions which compose the new transaction.
+   */
+  readonly instructions: TransactionInstruction[];
+  /**
+   * ETA of the new transaction.
+   */
+  readonly eta?: BN;
+}
+
 export interface NewTransactionArgs {
   readonly proposer?: PublicKey;
   /**
@@ -46,4 +82,12 @@ export interface NewTransactionArgs {
    * ETA of the new transaction.
    */
   readonly eta?: BN;
+  /**
+   * Lamports paid to the executor of the new transaction.
+   */
+  readonly bounty?: BN;
+  /**
+   * Index of the wallet-derived address which pays the bounty.
+   */
+  readonly bountyWalletIndex?: number;
 }
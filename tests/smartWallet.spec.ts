@@ -15,6 +15,7 @@ import {
 import { expect } from "chai";
 import invariant from "tiny-invariant";
 
+import { OWNER_PERMISSIONS } from "../src/constants";
 import { SmartWalletErrors } from "../src/idls/smart_wallet";
 import type { SmartWalletWrapper } from "../src/wrappers/smartWallet";
 import {
@@ -30,6 +31,17 @@ describe("smart wallet", () => {
   const sdk = makeSDK();
   const program = sdk.programs.SmartWallet;
 
+  const executeAsWallet = async (
+    wrapper: SmartWalletWrapper,
+    tx: TransactionEnvelope
+  ) => {
+    const { tx: proposeTx, transactionKey } = await wrapper.newTransaction({
+      instructions: tx.instructions,
+    });
+    await expectTX(proposeTx, "propose").to.be.fulfilled;
+    return await wrapper.executeTransaction({ transactionKey });
+  };
+
   describe("Tests the smartWallet program", () => {
     const smartWalletBase = web3.Keypair.generate();
     const numOwners = 10; // Big enough.
@@ -234,6 +246,151 @@ describe("smart wallet", () => {
         );
       }
     });
+
+    it("proposer can cancel a transaction", async () => {
+      const { provider } = sdk;
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        proposer: ownerA.publicKey,
+        instructions: [createMemoInstruction("cancelled")],
+      });
+      tx.signers.push(ownerA);
+      await expectTX(tx, "create new transaction").to.be.fulfilled;
+
+      const txAccount = await smartWalletWrapper.fetchTransaction(
+        transactionKey
+      );
+      expect(txAccount.payer).to.eqAddress(provider.wallet.publicKey);
+
+      const cancelByOther = await smartWalletWrapper.cancelTransaction(
+        transactionKey,
+        ownerB.publicKey
+      );
+      cancelByOther.addSigners(ownerB);
+      await expectTX(cancelByOther, "non-proposer cannot cancel").to.be
+        .rejected;
+
+      const balanceBefore = await provider.connection.getBalance(
+        provider.wallet.publicKey
+      );
+      const cancelTx = await smartWalletWrapper.cancelTransaction(
+        transactionKey,
+        ownerA.publicKey
+      );
+      cancelTx.addSigners(ownerA);
+      await expectTX(cancelTx, "cancel transaction").to.be.fulfilled;
+
+      expect(
+        await program.account.transaction.fetchNullable(transactionKey)
+      ).to.be.null;
+      expect(
+        await provider.connection.getBalance(provider.wallet.publicKey)
+      ).to.be.greaterThan(balanceBefore);
+    });
+
+    it("can change the grace period", async () => {
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.gracePeriod).to.bignumber.eq(
+        new BN(14 * 24 * 60 * 60)
+      );
+
+      const newGracePeriod = new BN(60 * 60);
+      const data = program.coder.instruction.encode("set_grace_period", {
+        gracePeriod: newGracePeriod,
+      });
+      const instruction = new TransactionInstruction({
+        programId: program.programId,
+        keys: [
+          {
+            pubkey: smartWalletWrapper.key,
+            isWritable: true,
+            isSigner: true,
+          },
+        ],
+        data,
+      });
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        proposer: ownerA.publicKey,
+        instructions: [instruction],
+      });
+      tx.signers.push(ownerA);
+      await expectTX(tx, "create new transaction").to.be.fulfilled;
+
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey,
+            owner: ownerA.publicKey,
+          })
+        ).addSigners(ownerA),
+        "execute transaction"
+      ).to.be.fulfilled;
+
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.gracePeriod).to.bignumber.eq(
+        newGracePeriod
+      );
+    });
+
+    it("raising the minimum delay invalidates queued transactions", async () => {
+      const { tx: queuedTx, transactionKey: queuedTransactionKey } =
+        await smartWalletWrapper.newTransaction({
+          proposer: ownerA.publicKey,
+          instructions: [createMemoInstruction("queued")],
+        });
+      queuedTx.signers.push(ownerA);
+      await expectTX(queuedTx, "queue transaction").to.be.fulfilled;
+
+      const newMinimumDelay = new BN(1);
+      const data = program.coder.instruction.encode("set_minimum_delay", {
+        minimumDelay: newMinimumDelay,
+      });
+      const instruction = new TransactionInstruction({
+        programId: program.programId,
+        keys: [
+          {
+            pubkey: smartWalletWrapper.key,
+            isWritable: true,
+            isSigner: true,
+          },
+        ],
+        data,
+      });
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        proposer: ownerA.publicKey,
+        instructions: [instruction],
+      });
+      tx.signers.push(ownerA);
+      await expectTX(tx, "create new transaction").to.be.fulfilled;
+
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey,
+            owner: ownerA.publicKey,
+          })
+        ).addSigners(ownerA),
+        "execute transaction"
+      ).to.be.fulfilled;
+
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.minimumDelay).to.bignumber.eq(
+        newMinimumDelay
+      );
+
+      const execQueuedTx = await smartWalletWrapper.executeTransaction({
+        transactionKey: queuedTransactionKey,
+        owner: ownerA.publicKey,
+      });
+      execQueuedTx.addSigners(ownerA);
+      try {
+        await execQueuedTx.confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.InvalidETA.code.toString(16)}`
+        );
+      }
+    });
   });
 
   describe("Tests the smartWallet program with timelock", () => {
@@ -439,6 +596,1938 @@ describe("smart wallet", () => {
         LAMPORTS_PER_SOL
       );
     });
+
+    it("can close executed transactions", async () => {
+      const { provider } = smartWalletWrapper;
+      const { transactionKey, tx } = await smartWalletWrapper.newTransaction({
+        proposer: provider.wallet.publicKey,
+        instructions: [createMemoInstruction("close me")],
+      });
+      await expectTX(tx, "create new transaction").to.be.fulfilled;
+
+      const closeUnexecuted = await smartWalletWrapper.closeTransaction(
+        transactionKey
+      );
+      try {
+        await closeUnexecuted.confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.TransactionNotClosable.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute transaction"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await smartWalletWrapper.closeTransaction(transactionKey),
+        "close transaction"
+      ).to.be.fulfilled;
+      expect(
+        await smartWalletWrapper.program.account.transaction.fetchNullable(
+          transactionKey
+        )
+      ).to.be.null;
+
+      const data = await smartWalletWrapper.reloadData();
+      expect(data.numClosedTransactions).to.bignumber.eq(new BN(1));
+    });
+
+    it("cannot close current transactions as legacy transactions", async () => {
+      const { provider } = smartWalletWrapper;
+      const { transactionKey, tx } = await smartWalletWrapper.newTransaction({
+        proposer: provider.wallet.publicKey,
+        instructions: [createMemoInstruction("not legacy")],
+      });
+      await expectTX(tx, "create new transaction").to.be.fulfilled;
+
+      try {
+        await smartWalletWrapper
+          .closeLegacyTransaction(transactionKey, provider.wallet.publicKey)
+          .confirm();
+        expect.fail("current transactions should not be closable as legacy");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.NotLegacyTransaction.code.toString(16)}`
+        );
+      }
+      expect(
+        await smartWalletWrapper.program.account.transaction.fetchNullable(
+          transactionKey
+        )
+      ).to.not.be.null;
+    });
+  });
+
+  describe("Owner management", () => {
+    const { provider } = sdk;
+    const ownerA = web3.Keypair.generate();
+    const ownerB = web3.Keypair.generate();
+    const ownerC = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 3,
+          owners: [provider.wallet.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    const walletInstruction = (name: string, args: Record<string, unknown>) =>
+      new TransactionEnvelope(provider, [
+        new TransactionInstruction({
+          programId: program.programId,
+          keys: [
+            {
+              pubkey: smartWalletWrapper.key,
+              isWritable: true,
+              isSigner: true,
+            },
+          ],
+          data: program.coder.instruction.encode(name, args),
+        }),
+      ]);
+
+    it("rejects invalid owner sets at creation", async () => {
+      const invalidConfigs = [
+        {
+          owners: [ownerA.publicKey, ownerA.publicKey],
+          threshold: new BN(1),
+          error: SmartWalletErrors.DuplicateOwner,
+        },
+        {
+          owners: [ownerA.publicKey],
+          threshold: new BN(0),
+          error: SmartWalletErrors.ZeroThreshold,
+        },
+        {
+          owners: [ownerA.publicKey],
+          threshold: new BN(2),
+          error: SmartWalletErrors.InvalidThreshold,
+        },
+        {
+          owners: [],
+          threshold: new BN(1),
+          error: SmartWalletErrors.EmptyOwners,
+        },
+      ];
+      for (const { owners, threshold, error } of invalidConfigs) {
+        const { tx } = await sdk.newSmartWallet({
+          numOwners: 3,
+          owners,
+          threshold,
+        });
+        try {
+          await tx.confirm();
+          expect.fail(`expected ${error.name}`);
+        } catch (e) {
+          const err = e as Error;
+          expect(err.message).to.include(`0x${error.code.toString(16)}`);
+        }
+      }
+    });
+
+    it("can add, swap, and remove owners", async () => {
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          walletInstruction("add_owner", { owner: ownerA.publicKey })
+        ),
+        "add owner A"
+      ).to.be.fulfilled;
+
+      try {
+        await (
+          await executeAsWallet(
+            smartWalletWrapper,
+            walletInstruction("add_owner", { owner: ownerA.publicKey })
+          )
+        ).confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.DuplicateOwner.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          walletInstruction("add_owner", { owner: ownerB.publicKey })
+        ),
+        "add owner B"
+      ).to.be.fulfilled;
+
+      try {
+        await (
+          await executeAsWallet(
+            smartWalletWrapper,
+            walletInstruction("add_owner", { owner: ownerC.publicKey })
+          )
+        ).confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.TooManyOwners.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          walletInstruction("swap_owner", {
+            oldOwner: ownerB.publicKey,
+            newOwner: ownerC.publicKey,
+          })
+        ),
+        "swap owner B for C"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          walletInstruction("remove_owner", {
+            owner: ownerA.publicKey,
+            threshold: new BN(1),
+          })
+        ),
+        "remove owner A"
+      ).to.be.fulfilled;
+
+      const data = await smartWalletWrapper.reloadData();
+      expect(data.owners).to.deep.equal([
+        provider.wallet.publicKey,
+        ownerC.publicKey,
+      ]);
+      expect(data.threshold).to.bignumber.eq(new BN(1));
+      expect(data.ownerSetSeqno).to.equal(4);
+    });
+
+    it("can grow the owner capacity", async () => {
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          walletInstruction("add_owner", { owner: ownerA.publicKey })
+        ),
+        "add owner A"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper.resizeSmartWallet(5),
+        "resize smart wallet"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          walletInstruction("add_owner", { owner: ownerB.publicKey })
+        ),
+        "add owner B"
+      ).to.be.fulfilled;
+
+      const data = await smartWalletWrapper.reloadData();
+      expect(data.owners).to.have.length(4);
+    });
+  });
+
+  describe("Weighted owners", () => {
+    const { provider } = sdk;
+    const ownerA = web3.Keypair.generate();
+    const ownerB = web3.Keypair.generate();
+    const owners = [
+      provider.wallet.publicKey,
+      ownerA.publicKey,
+      ownerB.publicKey,
+    ];
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: owners.length,
+          owners,
+          threshold: new BN(3),
+          ownerWeights: [new BN(3), new BN(1), new BN(1)],
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("threshold is compared against the weight of approvers", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        proposer: ownerA.publicKey,
+        instructions: [createMemoInstruction("weighted")],
+      });
+      tx.signers.push(ownerA);
+      await expectTX(tx, "create new transaction").to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "ownerB approves"
+      ).to.be.fulfilled;
+
+      try {
+        await (
+          await smartWalletWrapper.executeTransaction({ transactionKey })
+        ).confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.NotEnoughSigners.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        smartWalletWrapper.approveTransaction(transactionKey),
+        "heavy owner approves"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute transaction"
+      ).to.be.fulfilled;
+    });
+
+    it("cannot migrate a smart wallet using the current layout", async () => {
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.layoutVersion).to.bignumber.eq(
+        new BN(1)
+      );
+
+      try {
+        await smartWalletWrapper.migrateSmartWallet().confirm();
+        expect.fail("current smart wallet should not be migrated");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.WalletAlreadyMigrated.code.toString(16)}`
+        );
+      }
+    });
+  });
+
+  describe("Owner permissions", () => {
+    const { provider } = sdk;
+    const opsKey = web3.Keypair.generate();
+    const owners = [provider.wallet.publicKey, opsKey.publicKey];
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: owners.length,
+          owners,
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper.setOwnerPermissions([
+            OWNER_PERMISSIONS.All,
+            OWNER_PERMISSIONS.Propose | OWNER_PERMISSIONS.Execute,
+          ])
+        ),
+        "set owner permissions"
+      ).to.be.fulfilled;
+    });
+
+    it("operational keys can propose and execute but not approve", async () => {
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.ownerPermissions).to.deep.eq(
+        Buffer.from([
+          OWNER_PERMISSIONS.All,
+          OWNER_PERMISSIONS.Propose | OWNER_PERMISSIONS.Execute,
+        ])
+      );
+
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        proposer: opsKey.publicKey,
+        instructions: [createMemoInstruction("ops")],
+      });
+      tx.signers.push(opsKey);
+      await expectTX(tx, "ops key proposes").to.be.fulfilled;
+
+      try {
+        await smartWalletWrapper
+          .approveTransaction(transactionKey, opsKey.publicKey)
+          .addSigners(opsKey)
+          .confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.OwnerMissingPermission.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        smartWalletWrapper.approveTransaction(transactionKey),
+        "admin approves"
+      ).to.be.fulfilled;
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey,
+            owner: opsKey.publicKey,
+          })
+        ).addSigners(opsKey),
+        "ops key executes"
+      ).to.be.fulfilled;
+    });
+  });
+
+  describe("Allowed proposers", () => {
+    const { provider } = sdk;
+    const bot = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 1,
+          owners: [provider.wallet.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("non-owners with a proposer record can propose", async () => {
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          await smartWalletWrapper.addProposer(bot.publicKey)
+        ),
+        "add proposer"
+      ).to.be.fulfilled;
+
+      const { tx, transactionKey } =
+        await smartWalletWrapper.newProposerTransaction({
+          proposer: bot.publicKey,
+          instructions: [createMemoInstruction("from bot")],
+        });
+      tx.signers.push(bot);
+      await expectTX(tx, "bot proposes").to.be.fulfilled;
+
+      const txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.proposer).to.eqAddress(bot.publicKey);
+      expect(txData.signers).to.deep.eq([false]);
+
+      await expectTX(
+        smartWalletWrapper.approveTransaction(transactionKey),
+        "owner approves"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute transaction"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          await smartWalletWrapper.removeProposer(bot.publicKey)
+        ),
+        "remove proposer"
+      ).to.be.fulfilled;
+
+      const { tx: revokedTx } = await smartWalletWrapper.newProposerTransaction(
+        {
+          proposer: bot.publicKey,
+          instructions: [createMemoInstruction("revoked")],
+        }
+      );
+      revokedTx.signers.push(bot);
+      await expectTX(revokedTx, "revoked bot proposes").to.be.rejected;
+    });
+  });
+
+  describe("Execution policy", () => {
+    const { provider } = sdk;
+    const keeper = web3.Keypair.generate();
+    const stranger = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    const proposeMemo = async (memo: string) => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction(memo)],
+      });
+      await expectTX(tx, `propose ${memo}`).to.be.fulfilled;
+      return transactionKey;
+    };
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 1,
+          owners: [provider.wallet.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("keepers and anyone may execute when allowed", async () => {
+      const ownersOnlyKey = await proposeMemo("owners only");
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey: ownersOnlyKey,
+            owner: keeper.publicKey,
+          })
+        ).addSigners(keeper),
+        "keeper cannot execute by default"
+      ).to.be.rejected;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper.setExecutionPolicy("keepers", [keeper.publicKey])
+        ),
+        "set keepers policy"
+      ).to.be.fulfilled;
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.executionPolicy).to.deep.eq({
+        keepers: {},
+      });
+
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey: ownersOnlyKey,
+            owner: keeper.publicKey,
+          })
+        ).addSigners(keeper),
+        "keeper executes"
+      ).to.be.fulfilled;
+      const txData = await smartWalletWrapper.fetchTransaction(ownersOnlyKey);
+      expect(txData.executor).to.eqAddress(keeper.publicKey);
+
+      const strangerKey = await proposeMemo("stranger");
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey: strangerKey,
+            owner: stranger.publicKey,
+          })
+        ).addSigners(stranger),
+        "stranger cannot execute under keepers policy"
+      ).to.be.rejected;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper.setExecutionPolicy("anyone")
+        ),
+        "set anyone policy"
+      ).to.be.fulfilled;
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey: strangerKey,
+            owner: stranger.publicKey,
+          })
+        ).addSigners(stranger),
+        "stranger executes"
+      ).to.be.fulfilled;
+    });
+
+    it("cannot set more than the maximum number of keepers", async () => {
+      const keepers = Array.from(
+        { length: 9 },
+        () => web3.Keypair.generate().publicKey
+      );
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper.setExecutionPolicy("keepers", keepers)
+        ),
+        "too many keepers"
+      ).to.be.rejected;
+    });
+  });
+
+  describe("Keeper bounty", () => {
+    const { provider } = sdk;
+    const executor = web3.Keypair.generate();
+    const owners = [provider.wallet.publicKey, executor.publicKey];
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: owners.length,
+          owners,
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("pays the bounty from the derived wallet to the executor", async () => {
+      const bountyWalletIndex = 1;
+      const bounty = new BN(LAMPORTS_PER_SOL / 100);
+      const [bountyWallet] = await findWalletDerivedAddress(
+        smartWalletWrapper.key,
+        bountyWalletIndex
+      );
+      await expectTX(
+        new TransactionEnvelope(provider, [
+          SystemProgram.transfer({
+            fromPubkey: provider.wallet.publicKey,
+            toPubkey: bountyWallet,
+            lamports: LAMPORTS_PER_SOL,
+          }),
+        ]),
+        "fund bounty wallet"
+      ).to.be.fulfilled;
+
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("bounty")],
+        bounty,
+        bountyWalletIndex,
+      });
+      await expectTX(tx, "create transaction with bounty").to.be.fulfilled;
+      const txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.bounty).to.bignumber.eq(bounty);
+      expect(txData.bountyWalletIndex).to.bignumber.eq(
+        new BN(bountyWalletIndex)
+      );
+
+      await expectTX(
+        (
+          await smartWalletWrapper.executeTransaction({
+            transactionKey,
+            owner: executor.publicKey,
+          })
+        ).addSigners(executor),
+        "executor claims bounty"
+      ).to.be.fulfilled;
+
+      expect(await provider.connection.getBalance(executor.publicKey)).to.eq(
+        bounty.toNumber()
+      );
+      expect(await provider.connection.getBalance(bountyWallet)).to.eq(
+        LAMPORTS_PER_SOL - bounty.toNumber()
+      );
+    });
+  });
+
+  describe("Guardian", () => {
+    const { provider } = sdk;
+    const guardian = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 1,
+          owners: [provider.wallet.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper.setGuardian(guardian.publicKey)
+        ),
+        "set guardian"
+      ).to.be.fulfilled;
+    });
+
+    it("guardian can veto a queued transaction", async () => {
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.guardian).to.eqAddress(
+        guardian.publicKey
+      );
+
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("vetoed")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper.vetoTransaction(transactionKey),
+        "owner cannot veto"
+      ).to.be.rejected;
+      await expectTX(
+        smartWalletWrapper
+          .vetoTransaction(transactionKey, guardian.publicKey)
+          .addSigners(guardian),
+        "guardian vetoes"
+      ).to.be.fulfilled;
+
+      const txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.vetoer).to.eqAddress(guardian.publicKey);
+
+      try {
+        await (
+          await smartWalletWrapper.executeTransaction({ transactionKey })
+        ).confirm();
+        expect.fail("vetoed transaction should not execute");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.TransactionVetoed.code.toString(16)}`
+        );
+      }
+    });
+
+    it("guardian can veto a transaction buffer", async () => {
+      const { bufferKey, tx: initTx } =
+        await smartWalletWrapper.initTransactionBuffer({ space: 1_000 });
+      await expectTX(initTx, "init transaction buffer").to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.writeTransactionBuffer({
+          bufferKey,
+          bundleIndex: 0,
+          instructions: [createMemoInstruction("vetoed buffer")],
+        }),
+        "write buffer"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.finalizeTransactionBuffer(bufferKey),
+        "finalize buffer"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.approveTransactionBuffer(bufferKey),
+        "approve buffer"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper.vetoTransactionBuffer(bufferKey),
+        "owner cannot veto"
+      ).to.be.rejected;
+      await expectTX(
+        smartWalletWrapper
+          .vetoTransactionBuffer(bufferKey, guardian.publicKey)
+          .addSigners(guardian),
+        "guardian vetoes buffer"
+      ).to.be.fulfilled;
+
+      const buffer = await smartWalletWrapper.fetchTransactionBuffer(bufferKey);
+      expect(buffer.vetoer).to.eqAddress(guardian.publicKey);
+
+      try {
+        await (
+          await smartWalletWrapper.executeBufferBundle({
+            bufferKey,
+            bundleIndex: 0,
+          })
+        ).confirm();
+        expect.fail("vetoed buffer should not execute");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.TransactionVetoed.code.toString(16)}`
+        );
+      }
+    });
+  });
+
+  describe("Emergency pause", () => {
+    const { provider } = sdk;
+    const pauser = web3.Keypair.generate();
+    const ownerA = web3.Keypair.generate();
+    const owners = [provider.wallet.publicKey, ownerA.publicKey];
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: owners.length,
+          owners,
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper.setPauseAuthority(pauser.publicKey, 2)
+        ),
+        "set pause authority"
+      ).to.be.fulfilled;
+    });
+
+    it("pause blocks execution until unpaused by governance", async () => {
+      await expectTX(
+        smartWalletWrapper.pause(),
+        "a single owner cannot pause"
+      ).to.be.rejected;
+      await expectTX(
+        smartWalletWrapper
+          .pause({ authority: pauser.publicKey })
+          .addSigners(pauser),
+        "pause authority pauses"
+      ).to.be.fulfilled;
+
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("paused")],
+      });
+      await expectTX(tx, "propose while paused").to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(transactionKey, ownerA.publicKey)
+          .addSigners(ownerA),
+        "approve while paused"
+      ).to.be.fulfilled;
+
+      try {
+        await (
+          await smartWalletWrapper.executeTransaction({ transactionKey })
+        ).confirm();
+        expect.fail("paused wallet should not execute");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.WalletPaused.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        await executeAsWallet(smartWalletWrapper, smartWalletWrapper.unpause()),
+        "unpause"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute after unpause"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper
+          .pause({ owners: [ownerA.publicKey] })
+          .addSigners(ownerA),
+        "two owners pause together"
+      ).to.be.fulfilled;
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.pausedAt.toNumber()).to.not.eq(0);
+    });
+
+    it("remaining owners may pause after owners are removed", async () => {
+      await expectTX(
+        await executeAsWallet(smartWalletWrapper, smartWalletWrapper.unpause()),
+        "unpause"
+      ).to.be.fulfilled;
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper.removeOwner(ownerA.publicKey, 1)
+        ),
+        "remove owner A"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper.pause(),
+        "last owner pauses"
+      ).to.be.fulfilled;
+    });
+  });
+
+  describe("Social recovery", () => {
+    const { provider } = sdk;
+    const recoveryKeyA = web3.Keypair.generate();
+    const recoveryKeyB = web3.Keypair.generate();
+    const newOwner = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    const initiateRecovery = async () =>
+      (
+        await smartWalletWrapper.initiateRecovery({
+          newOwners: [newOwner.publicKey],
+          newThreshold: new BN(1),
+          initiator: recoveryKeyA.publicKey,
+          recoveryKeys: [recoveryKeyB.publicKey],
+        })
+      ).addSigners(recoveryKeyA, recoveryKeyB);
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 2,
+          owners: [provider.wallet.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+      await smartWalletWrapper.reloadData();
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          await smartWalletWrapper.createRecovery({
+            recoveryKeys: [recoveryKeyA.publicKey, recoveryKeyB.publicKey],
+            threshold: new BN(2),
+            delay: new BN(2),
+            maxOwners: 2,
+          })
+        ),
+        "create recovery"
+      ).to.be.fulfilled;
+    });
+
+    it("recovery keys can replace the owners after the delay", async () => {
+      await expectTX(
+        (
+          await smartWalletWrapper.initiateRecovery({
+            newOwners: [newOwner.publicKey],
+            newThreshold: new BN(1),
+            initiator: recoveryKeyA.publicKey,
+          })
+        ).addSigners(recoveryKeyA),
+        "a single recovery key cannot initiate"
+      ).to.be.rejected;
+
+      await expectTX(await initiateRecovery(), "initiate recovery").to.be
+        .fulfilled;
+      await expectTX(
+        await smartWalletWrapper.cancelRecovery(),
+        "owner cancels recovery"
+      ).to.be.fulfilled;
+      const recovery = await smartWalletWrapper.fetchRecovery();
+      expect(recovery?.initiatedAt).to.bignumber.eq(new BN(0));
+
+      await expectTX(await initiateRecovery(), "initiate recovery again").to.be
+        .fulfilled;
+      await expectTX(
+        await smartWalletWrapper.completeRecovery(),
+        "cannot complete before the delay"
+      ).to.be.rejected;
+
+      await sleep(3000);
+      await expectTX(
+        await smartWalletWrapper.completeRecovery(),
+        "complete recovery"
+      ).to.be.fulfilled;
+
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.owners).to.deep.eq([newOwner.publicKey]);
+      expect(smartWalletWrapper.data?.ownerSetSeqno).to.eq(1);
+    });
+  });
+
+  describe("Dead man's switch", () => {
+    const { provider } = sdk;
+    const heir = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 1,
+          owners: [provider.wallet.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          await smartWalletWrapper.createBeneficiary({
+            owners: [heir.publicKey],
+            threshold: new BN(1),
+            inactivityPeriod: new BN(2),
+          })
+        ),
+        "create beneficiary"
+      ).to.be.fulfilled;
+    });
+
+    it("beneficiary can claim the wallet after inactivity", async () => {
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.lastActivityTs.toNumber()).to.be.gt(0);
+
+      await expectTX(
+        (
+          await smartWalletWrapper.claimInactiveWallet(heir.publicKey)
+        ).addSigners(heir),
+        "cannot claim an active wallet"
+      ).to.be.rejected;
+
+      await sleep(3000);
+      await expectTX(
+        (
+          await smartWalletWrapper.claimInactiveWallet(heir.publicKey)
+        ).addSigners(heir),
+        "claim inactive wallet"
+      ).to.be.fulfilled;
+
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.owners).to.deep.eq([heir.publicKey]);
+    });
+  });
+
+  describe("Threshold decay", () => {
+    const { provider } = sdk;
+    const ownerB = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 2,
+          owners: [provider.wallet.publicKey, ownerB.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper
+            .setThresholdDecay(new BN(2), new BN(1))
+            .combine(smartWalletWrapper.changeThreshold(2))
+        ),
+        "set threshold decay"
+      ).to.be.fulfilled;
+    });
+
+    it("threshold decays towards the floor over time", async () => {
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.threshold).to.bignumber.eq(new BN(2));
+      expect(smartWalletWrapper.data?.thresholdDecayPeriod).to.bignumber.eq(
+        new BN(2)
+      );
+
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("decayed")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "not enough approvals yet"
+      ).to.be.rejected;
+
+      await sleep(3000);
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute after decay"
+      ).to.be.fulfilled;
+    });
+  });
+
+  describe("Reject votes", () => {
+    const { provider } = sdk;
+    const ownerB = web3.Keypair.generate();
+    const ownerC = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 3,
+          owners: [
+            provider.wallet.publicKey,
+            ownerB.publicKey,
+            ownerC.publicKey,
+          ],
+          threshold: new BN(2),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("rejects once the threshold is unreachable", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("rejected")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper
+          .rejectTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "owner B rejects"
+      ).to.be.fulfilled;
+      let txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.rejections).to.deep.eq([false, true, false]);
+      expect(txData.rejectedAt.toNumber()).to.eq(0);
+
+      await expectTX(
+        smartWalletWrapper
+          .rejectTransaction(transactionKey, ownerC.publicKey)
+          .addSigners(ownerC),
+        "owner C rejects"
+      ).to.be.fulfilled;
+      txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.rejectedAt.toNumber()).to.be.gt(0);
+
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "rejections are final"
+      ).to.be.rejected;
+
+      try {
+        await (
+          await smartWalletWrapper.executeTransaction({ transactionKey })
+        ).confirm();
+        expect.fail("rejected transaction should not execute");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.TransactionRejected.code.toString(16)}`
+        );
+      }
+    });
+  });
+
+  describe("Timelock from threshold", () => {
+    const { provider } = sdk;
+    const ownerB = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 2,
+          owners: [provider.wallet.publicKey, ownerB.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+
+      await expectTX(
+        await executeAsWallet(
+          smartWalletWrapper,
+          smartWalletWrapper
+            .setTimelockMode("thresholdReached")
+            .combine(smartWalletWrapper.setMinimumDelay(new BN(2)))
+            .combine(smartWalletWrapper.changeThreshold(2))
+        ),
+        "set timelock mode"
+      ).to.be.fulfilled;
+    });
+
+    it("delay counts from the final approval", async () => {
+      await smartWalletWrapper.reloadData();
+      expect(smartWalletWrapper.data?.timelockMode).to.deep.eq({
+        thresholdReached: {},
+      });
+
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("timelocked")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+      let txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.thresholdReachedAt.toNumber()).to.eq(0);
+
+      // the proposal sits unapproved through the whole delay
+      await sleep(3000);
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "owner B approves"
+      ).to.be.fulfilled;
+      txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.thresholdReachedAt.toNumber()).to.be.gt(0);
+
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "delay has not elapsed since the threshold was reached"
+      ).to.be.rejected;
+
+      await sleep(3000);
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute after delay"
+      ).to.be.fulfilled;
+    });
+
+    it("buffer delay counts from the final approval", async () => {
+      const { bufferKey, tx: initTx } =
+        await smartWalletWrapper.initTransactionBuffer({ space: 1_000 });
+      await expectTX(initTx, "init transaction buffer").to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.writeTransactionBuffer({
+          bufferKey,
+          bundleIndex: 0,
+          instructions: [createMemoInstruction("timelocked buffer")],
+        }),
+        "write buffer"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.finalizeTransactionBuffer(bufferKey),
+        "finalize buffer"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.approveTransactionBuffer(bufferKey),
+        "owner A approves"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper
+          .approveTransactionBuffer(bufferKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "owner B approves"
+      ).to.be.fulfilled;
+      const buffer = await smartWalletWrapper.fetchTransactionBuffer(bufferKey);
+      expect(buffer.thresholdReachedAt.toNumber()).to.be.gt(0);
+
+      await expectTX(
+        await smartWalletWrapper.executeBufferBundle({
+          bufferKey,
+          bundleIndex: 0,
+        }),
+        "delay has not elapsed since the threshold was reached"
+      ).to.be.rejected;
+
+      await sleep(3000);
+      await expectTX(
+        await smartWalletWrapper.executeBufferBundle({
+          bufferKey,
+          bundleIndex: 0,
+        }),
+        "execute bundle after delay"
+      ).to.be.fulfilled;
+    });
+
+    it("delay counts from when the threshold decayed", async () => {
+      const { smartWalletWrapper: decayWallet, tx: createTx } =
+        await sdk.newSmartWallet({
+          numOwners: 2,
+          owners: [provider.wallet.publicKey, ownerB.publicKey],
+          threshold: new BN(1),
+        });
+      await expectTX(createTx, "create new smartWallet").to.be.fulfilled;
+      await expectTX(
+        await executeAsWallet(
+          decayWallet,
+          decayWallet
+            .setTimelockMode("thresholdReached")
+            .combine(decayWallet.setMinimumDelay(new BN(2)))
+            .combine(decayWallet.setThresholdDecay(new BN(2), new BN(1)))
+            .combine(decayWallet.changeThreshold(2))
+        ),
+        "set timelock mode and threshold decay"
+      ).to.be.fulfilled;
+
+      // only the proposer approves, so the threshold is reached by decay
+      const { tx, transactionKey } = await decayWallet.newTransaction({
+        instructions: [createMemoInstruction("decayed")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+      const txData = await decayWallet.fetchTransaction(transactionKey);
+      expect(txData.thresholdReachedAt.toNumber()).to.eq(0);
+
+      await sleep(5000);
+      await expectTX(
+        await decayWallet.executeTransaction({ transactionKey }),
+        "execute after decay and delay"
+      ).to.be.fulfilled;
+    });
+
+    it("lowering the threshold does not skip the delay", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("lowered")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      const { tx: lowerTx, transactionKey: lowerKey } =
+        await smartWalletWrapper.newTransaction({
+          instructions: smartWalletWrapper.changeThreshold(1).instructions,
+        });
+      await expectTX(lowerTx, "propose lowering the threshold").to.be
+        .fulfilled;
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(lowerKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "owner B approves"
+      ).to.be.fulfilled;
+      await sleep(3000);
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({
+          transactionKey: lowerKey,
+        }),
+        "lower the threshold"
+      ).to.be.fulfilled;
+
+      try {
+        await (
+          await smartWalletWrapper.executeTransaction({ transactionKey })
+        ).confirm();
+        expect.fail("no approval reached the lowered threshold");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.NotEnoughSigners.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        smartWalletWrapper.approveTransaction(transactionKey),
+        "owner A approves again"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "delay has not elapsed since the approval"
+      ).to.be.rejected;
+    });
+  });
+
+  describe("Off-chain approvals", () => {
+    const { provider } = sdk;
+    const ownerB = web3.Keypair.generate();
+    const ownerC = web3.Keypair.generate();
+    const stranger = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 3,
+          owners: [
+            provider.wallet.publicKey,
+            ownerB.publicKey,
+            ownerC.publicKey,
+          ],
+          threshold: new BN(3),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("relayer submits several signed approvals at once", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("gasless")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      const message = await smartWalletWrapper.approvalMessage(transactionKey);
+      await expectTX(
+        smartWalletWrapper.approveWithSignatures(transactionKey, [
+          web3.Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: stranger.secretKey,
+            message,
+          }),
+        ]),
+        "non-owner signatures do not approve"
+      ).to.be.rejected;
+
+      await expectTX(
+        smartWalletWrapper.approveWithSignatures(
+          transactionKey,
+          [ownerB, ownerC].map((owner) =>
+            web3.Ed25519Program.createInstructionWithPrivateKey({
+              privateKey: owner.secretKey,
+              message,
+            })
+          )
+        ),
+        "approve with signatures"
+      ).to.be.fulfilled;
+
+      const txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.signers).to.deep.eq([true, true, true]);
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute transaction"
+      ).to.be.fulfilled;
+    });
+
+    it("signatures of owners which already approved are ignored", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("approved twice")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      const signature = web3.Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: ownerB.secretKey,
+        message: await smartWalletWrapper.approvalMessage(transactionKey),
+      });
+      await expectTX(
+        smartWalletWrapper.approveWithSignatures(transactionKey, [signature]),
+        "approve with signature"
+      ).to.be.fulfilled;
+
+      try {
+        await smartWalletWrapper
+          .approveWithSignatures(transactionKey, [
+            signature,
+            web3.Ed25519Program.createInstructionWithPrivateKey({
+              privateKey: stranger.secretKey,
+              message: await smartWalletWrapper.approvalMessage(transactionKey),
+            }),
+          ])
+          .confirm();
+        expect.fail("owner B has already approved");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.NoApprovalSignatures.code.toString(16)}`
+        );
+      }
+    });
+
+    it("signatures cannot be replayed after unapproving", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [createMemoInstruction("revoked")],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      const signature = web3.Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: ownerB.secretKey,
+        message: await smartWalletWrapper.approvalMessage(transactionKey),
+      });
+      await expectTX(
+        smartWalletWrapper.approveWithSignatures(transactionKey, [signature]),
+        "approve with signature"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper
+          .unapproveTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "owner B unapproves"
+      ).to.be.fulfilled;
+
+      try {
+        await smartWalletWrapper
+          .approveWithSignatures(transactionKey, [signature])
+          .confirm();
+        expect.fail("replayed signature should not approve");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.NoApprovalSignatures.code.toString(16)}`
+        );
+      }
+      let txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.signers).to.deep.eq([true, false, false]);
+      expect(txData.revoked).to.deep.eq([false, true, false]);
+
+      // approving on-chain again lifts the revocation
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "owner B approves on-chain"
+      ).to.be.fulfilled;
+      txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.signers).to.deep.eq([true, true, false]);
+      expect(txData.revoked).to.deep.eq([false, false, false]);
+    });
+  });
+
+  describe("Approve with hash", () => {
+    const { provider } = sdk;
+    const ownerB = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 2,
+          owners: [provider.wallet.publicKey, ownerB.publicKey],
+          threshold: new BN(2),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("approval fails unless the transaction hash matches", async () => {
+      const instructions = [createMemoInstruction("hashed")];
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions,
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      const txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      const instructionsHash = smartWalletWrapper.hashInstructions(txData);
+      expect(txData.instructionsHash).to.deep.eq([...instructionsHash]);
+
+      const wrongHashes = [
+        smartWalletWrapper.hashInstructions({
+          ...txData,
+          instructions: [
+            { ...txData.instructions[0], data: Buffer.from("tampered") },
+          ],
+        }),
+        smartWalletWrapper.hashInstructions({ ...txData, eta: new BN(1) }),
+        smartWalletWrapper.hashInstructions({ ...txData, bounty: new BN(1) }),
+      ];
+      for (const wrongHash of wrongHashes) {
+        await expectTX(
+          smartWalletWrapper
+            .approveTransactionWithHash(
+              transactionKey,
+              wrongHash,
+              ownerB.publicKey
+            )
+            .addSigners(ownerB),
+          "mismatched hash"
+        ).to.be.rejected;
+      }
+
+      await expectTX(
+        smartWalletWrapper
+          .approveTransactionWithHash(
+            transactionKey,
+            instructionsHash,
+            ownerB.publicKey
+          )
+          .addSigners(ownerB),
+        "approve with hash"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute transaction"
+      ).to.be.fulfilled;
+    });
+  });
+
+  describe("Nonce transactions", () => {
+    const { provider } = sdk;
+    const ownerB = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 2,
+          owners: [provider.wallet.publicKey, ownerB.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("concurrent proposers do not collide", async () => {
+      // both proposals are built before either is sent
+      const { tx: txA, transactionKey: keyA } =
+        await smartWalletWrapper.newTransactionWithNonce({
+          nonce: new BN(1),
+          instructions: [createMemoInstruction("proposer A")],
+        });
+      const { tx: txB, transactionKey: keyB } =
+        await smartWalletWrapper.newTransactionWithNonce({
+          proposer: ownerB.publicKey,
+          nonce: new BN(1),
+          instructions: [createMemoInstruction("proposer B")],
+        });
+      await expectTX(txA, "create transaction A").to.be.fulfilled;
+      await expectTX(txB.addSigners(ownerB), "create transaction B").to.be
+        .fulfilled;
+
+      const txDataA = await smartWalletWrapper.fetchTransaction(keyA);
+      const txDataB = await smartWalletWrapper.fetchTransaction(keyB);
+      expect(txDataA.index.toNumber()).to.eq(0);
+      expect(txDataB.index.toNumber()).to.eq(1);
+
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey: keyB }),
+        "execute transaction B"
+      ).to.be.fulfilled;
+    });
+
+    it("signatures do not carry over to a re-created transaction", async () => {
+      const nonce = new BN(2);
+      const instructions = [createMemoInstruction("re-created")];
+      const { tx, transactionKey } =
+        await smartWalletWrapper.newTransactionWithNonce({
+          nonce,
+          instructions,
+        });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+      const signature = web3.Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: ownerB.secretKey,
+        message: await smartWalletWrapper.approvalMessage(transactionKey),
+      });
+
+      await expectTX(
+        await smartWalletWrapper.cancelTransaction(transactionKey),
+        "cancel transaction"
+      ).to.be.fulfilled;
+
+      const { tx: recreateTx, transactionKey: recreatedKey } =
+        await smartWalletWrapper.newTransactionWithNonce({
+          nonce,
+          instructions,
+        });
+      expect(recreatedKey).to.eqAddress(transactionKey);
+      await expectTX(recreateTx, "re-create transaction").to.be.fulfilled;
+
+      try {
+        await smartWalletWrapper
+          .approveWithSignatures(transactionKey, [signature])
+          .confirm();
+        expect.fail("signature for the closed transaction should not approve");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.NoApprovalSignatures.code.toString(16)}`
+        );
+      }
+    });
+  });
+
+  describe("Draft transactions", () => {
+    const { provider } = sdk;
+    const ownerB = web3.Keypair.generate();
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 2,
+          owners: [provider.wallet.publicKey, ownerB.publicKey],
+          threshold: new BN(2),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("proposer edits a draft before approvals open", async () => {
+      const { tx, transactionKey } =
+        await smartWalletWrapper.newDraftTransaction({
+          instructions: [createMemoInstruction("tpyo")],
+        });
+      await expectTX(tx, "create draft").to.be.fulfilled;
+
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "drafts cannot be approved"
+      ).to.be.rejected;
+
+      await expectTX(
+        smartWalletWrapper.replaceDraftInstruction(
+          transactionKey,
+          0,
+          createMemoInstruction("typo fixed")
+        ),
+        "replace instruction"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.appendDraftInstruction(
+          transactionKey,
+          createMemoInstruction("a much longer memo which needs more space")
+        ),
+        "append instruction"
+      ).to.be.fulfilled;
+      await expectTX(
+        smartWalletWrapper.removeDraftInstruction(transactionKey, 1),
+        "remove instruction"
+      ).to.be.fulfilled;
+
+      let txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.draft).to.be.true;
+      expect(txData.instructions).to.have.length(1);
+      expect(Buffer.from(txData.instructions[0]?.data ?? [])).to.deep.eq(
+        Buffer.from("typo fixed")
+      );
+
+      await expectTX(
+        smartWalletWrapper.finalizeTransaction(transactionKey),
+        "finalize transaction"
+      ).to.be.fulfilled;
+      txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.draft).to.be.false;
+      expect(txData.signers).to.deep.eq([true, false]);
+
+      await expectTX(
+        smartWalletWrapper
+          .approveTransaction(transactionKey, ownerB.publicKey)
+          .addSigners(ownerB),
+        "approve finalized transaction"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.executeTransaction({ transactionKey }),
+        "execute transaction"
+      ).to.be.fulfilled;
+    });
+  });
+
+  describe("Stepwise execution", () => {
+    const { provider } = sdk;
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: 1,
+          owners: [provider.wallet.publicKey],
+          threshold: new BN(1),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("executes a transaction across several steps", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [
+          createMemoInstruction("step 1"),
+          createMemoInstruction("step 2"),
+          createMemoInstruction("step 3"),
+        ],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      await expectTX(
+        await smartWalletWrapper.executeTransactionStep({
+          transactionKey,
+          numInstructions: 2,
+        }),
+        "execute first step"
+      ).to.be.fulfilled;
+      let txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.executedCount.toNumber()).to.eq(2);
+      expect(txData.executedAt.toNumber()).to.eq(-1);
+
+      try {
+        await smartWalletWrapper.unapproveTransaction(transactionKey).confirm();
+        expect.fail("votes on a partially executed transaction are locked");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.ExecutionStarted.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        await smartWalletWrapper.executeTransactionStep({
+          transactionKey,
+          numInstructions: 2,
+        }),
+        "execute last step"
+      ).to.be.fulfilled;
+      txData = await smartWalletWrapper.fetchTransaction(transactionKey);
+      expect(txData.executedCount.toNumber()).to.eq(3);
+      expect(txData.executedAt.toNumber()).to.be.gt(0);
+
+      await expectTX(
+        await smartWalletWrapper.executeTransactionStep({
+          transactionKey,
+          numInstructions: 1,
+        }),
+        "already executed"
+      ).to.be.rejected;
+    });
+
+    it("proposer may abort a partially executed transaction", async () => {
+      const { tx, transactionKey } = await smartWalletWrapper.newTransaction({
+        instructions: [
+          createMemoInstruction("step 1"),
+          createMemoInstruction("step 2"),
+        ],
+      });
+      await expectTX(tx, "create transaction").to.be.fulfilled;
+
+      await expectTX(
+        await smartWalletWrapper.executeTransactionStep({
+          transactionKey,
+          numInstructions: 1,
+        }),
+        "execute first step"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.cancelTransaction(transactionKey),
+        "cancel remaining steps"
+      ).to.be.fulfilled;
+      expect(
+        await program.account.transaction.fetchNullable(transactionKey)
+      ).to.be.null;
+    });
+  });
+
+  describe("Transaction buffer", () => {
+    const { provider } = sdk;
+    const ownerA = web3.Keypair.generate();
+    const owners = [provider.wallet.publicKey, ownerA.publicKey];
+    let smartWalletWrapper: SmartWalletWrapper;
+
+    before(async () => {
+      const { smartWalletWrapper: wrapperInner, tx } = await sdk.newSmartWallet(
+        {
+          numOwners: owners.length,
+          owners,
+          threshold: new BN(2),
+        }
+      );
+      await expectTX(tx, "create new smartWallet").to.be.fulfilled;
+      smartWalletWrapper = wrapperInner;
+    });
+
+    it("can write, finalize, approve, and execute bundles", async () => {
+      const { bufferKey, tx: initTx } =
+        await smartWalletWrapper.initTransactionBuffer({ space: 2_000 });
+      await expectTX(initTx, "init transaction buffer").to.be.fulfilled;
+
+      const memos = ["first", "second", "third"];
+      for (const [bundleIndex, memo] of memos.entries()) {
+        await expectTX(
+          smartWalletWrapper.writeTransactionBuffer({
+            bufferKey,
+            bundleIndex: Math.min(bundleIndex, 1),
+            instructions: [createMemoInstruction(memo)],
+          }),
+          `write ${memo}`
+        ).to.be.fulfilled;
+      }
+
+      try {
+        await smartWalletWrapper
+          .approveTransactionBuffer(bufferKey, ownerA.publicKey)
+          .addSigners(ownerA)
+          .confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.BufferBundleNotFinalized.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        smartWalletWrapper.finalizeTransactionBuffer(bufferKey),
+        "finalize"
+      ).to.be.fulfilled;
+
+      try {
+        await smartWalletWrapper
+          .writeTransactionBuffer({
+            bufferKey,
+            bundleIndex: 0,
+            instructions: [createMemoInstruction("too late")],
+          })
+          .confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.BufferFinalized.code.toString(16)}`
+        );
+      }
+
+      const buffer = await smartWalletWrapper.fetchTransactionBuffer(bufferKey);
+      expect(buffer.bundles).to.have.length(2);
+      expect(buffer.bundles[1]?.instructions).to.have.length(2);
+
+      await expectTX(
+        smartWalletWrapper
+          .approveTransactionBuffer(bufferKey, ownerA.publicKey)
+          .addSigners(ownerA),
+        "approve"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await smartWalletWrapper.executeBufferBundle({
+          bufferKey,
+          bundleIndex: 1,
+        }),
+        "execute bundle 1"
+      ).to.be.fulfilled;
+      await expectTX(
+        await smartWalletWrapper.executeBufferBundle({
+          bufferKey,
+          bundleIndex: 0,
+        }),
+        "execute bundle 0"
+      ).to.be.fulfilled;
+
+      try {
+        await (
+          await smartWalletWrapper.executeBufferBundle({
+            bufferKey,
+            bundleIndex: 0,
+          })
+        ).confirm();
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.BufferBundleExecuted.code.toString(16)}`
+        );
+      }
+
+      try {
+        await smartWalletWrapper
+          .approveTransactionBuffer(bufferKey, ownerA.publicKey)
+          .addSigners(ownerA)
+          .confirm();
+        expect.fail("approvals should be frozen once a bundle is executed");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.BufferBundleExecuted.code.toString(16)}`
+        );
+      }
+
+      await expectTX(
+        await smartWalletWrapper.closeTransactionBuffer(bufferKey),
+        "close executed buffer"
+      ).to.be.fulfilled;
+      expect(
+        await program.account.transactionBuffer.fetchNullable(bufferKey)
+      ).to.be.null;
+    });
+
+    it("cannot write past the allocated space", async () => {
+      const { bufferKey, tx: initTx } =
+        await smartWalletWrapper.initTransactionBuffer({ space: 300 });
+      await expectTX(initTx, "init transaction buffer").to.be.fulfilled;
+
+      try {
+        await smartWalletWrapper
+          .writeTransactionBuffer({
+            bufferKey,
+            bundleIndex: 0,
+            instructions: [createMemoInstruction("x".repeat(200))],
+          })
+          .confirm();
+        expect.fail("write should not fit in the buffer");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.BufferFull.code.toString(16)}`
+        );
+      }
+
+      try {
+        await (
+          await smartWalletWrapper.closeTransactionBuffer(bufferKey)
+        ).confirm();
+        expect.fail("pending buffer should not be closable");
+      } catch (e) {
+        const err = e as Error;
+        expect(err.message).to.include(
+          `0x${SmartWalletErrors.TransactionNotClosable.code.toString(16)}`
+        );
+      }
+    });
   });
 
   describe("Owner Invoker", () => {